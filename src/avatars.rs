@@ -0,0 +1,268 @@
+use std::{
+    collections::HashMap,
+    fs::{read_to_string, remove_file},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use log::{debug, info, warn};
+use reqwest::{
+    blocking::Client,
+    header::{CONTENT_TYPE, ETAG, IF_NONE_MATCH},
+    StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    output::{write_atomic, User},
+    VERSION,
+};
+
+/// Size in pixels that avatars are downloaded at, twice the
+/// size they are displayed at on the site
+const AVATAR_SIZE: u32 = 160;
+
+/// Name of the file within the avatar directory that stores
+/// the state of the mirrored avatars
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// Errors that could occur while mirroring avatars
+#[derive(Debug, Error)]
+pub enum AvatarError {
+    #[error("Failed to request avatar: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("Unexpected response status: {0}")]
+    Status(StatusCode),
+    #[error("Failed to write avatar: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Failed to serialize manifest: {0}")]
+    Serialize(#[from] serde_json::Error),
+}
+
+/// State of an avatar that has been mirrored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MirroredAvatar {
+    /// GitHub URL the avatar was mirrored from
+    source: String,
+    /// ETag of the mirrored avatar
+    etag: Option<String>,
+    /// Name of the file within the avatar directory
+    file: String,
+}
+
+/// Downloads the avatars of users into a local directory so
+/// that the site doesn't need to hot-link GitHub
+pub struct AvatarMirror {
+    /// Client for downloading the avatars
+    client: Client,
+    /// Directory the avatars are stored in
+    dir: PathBuf,
+    /// Prefix of the paths the avatars are rewritten to
+    prefix: String,
+    /// Mirrored avatars by the node ID of their user, or their login
+    /// for users without one
+    manifest: Mutex<HashMap<String, MirroredAvatar>>,
+}
+
+impl AvatarMirror {
+    /// Creates a new mirror storing avatars in the provided directory
+    ///
+    /// # Arguments
+    /// * dir - The directory to store the avatars in
+    /// * prefix - The prefix of the paths the avatars are rewritten to
+    pub fn new(dir: PathBuf, prefix: String) -> Result<Self, AvatarError> {
+        let client = Client::builder()
+            .user_agent(format!("Committer/{}", VERSION))
+            .build()?;
+
+        // A missing or corrupt manifest only means avatars are downloaded again
+        let manifest = read_to_string(dir.join(MANIFEST_FILE))
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+
+        Ok(Self {
+            client,
+            dir,
+            prefix,
+            manifest: Mutex::new(manifest),
+        })
+    }
+
+    /// Mirrors the avatars of the provided users and rewrites their
+    /// avatar to the local path, users whose avatar couldn't be mirrored
+    /// keep linking to GitHub
+    ///
+    /// # Arguments
+    /// * users - The users to mirror the avatars of
+    pub fn mirror(&self, users: &mut [User]) {
+        for user in users {
+            // Users kept from a previous snapshot may already link to their
+            // mirrored avatar
+            if user.avatar.starts_with(&self.prefix) {
+                continue;
+            }
+
+            match self.mirror_avatar(user) {
+                Ok(file) => user.avatar = format!("{}{}", self.prefix, file),
+                Err(err) => warn!("Failed to mirror avatar for {}: {}", user.login, err),
+            }
+        }
+    }
+
+    /// Writes the manifest of mirrored avatars so that unchanged
+    /// avatars can be skipped by the next crawl
+    pub fn save(&self) -> Result<(), AvatarError> {
+        let manifest = self.manifest.lock().expect("Avatar manifest lock poisoned");
+        let json = serde_json::to_string_pretty(&*manifest)?;
+        write_atomic(&self.dir.join(MANIFEST_FILE), json.as_bytes())?;
+        Ok(())
+    }
+
+    /// Mirrors the avatar of a user, providing the name of the file
+    /// the avatar is stored in. Avatars are stored by the account of
+    /// their user so that renamed users keep their avatar
+    ///
+    /// # Arguments
+    /// * user - The user to mirror the avatar of
+    fn mirror_avatar(&self, user: &User) -> Result<String, AvatarError> {
+        let login = user.login.as_str();
+        let source = user.avatar.as_str();
+        let key = user.id.clone().unwrap_or_else(|| user.login.clone());
+
+        // Avatars mirrored before users had IDs are stored by their login
+        let previous = {
+            let mut manifest = self.manifest.lock().expect("Avatar manifest lock poisoned");
+            let previous = match manifest.get(&key) {
+                Some(previous) => Some(previous.clone()),
+                None => manifest.remove(login),
+            };
+            previous.filter(|previous| self.dir.join(&previous.file).exists())
+        };
+
+        if let Some(previous) = &previous {
+            // Uploaded avatars have a u= hash in their URL which changes along
+            // with the avatar, other avatars are checked using their ETag
+            if previous.source == source && has_avatar_hash(source) {
+                let file = previous.file.clone();
+                self.manifest
+                    .lock()
+                    .expect("Avatar manifest lock poisoned")
+                    .insert(key, previous.clone());
+                return Ok(file);
+            }
+        }
+
+        let mut request = self.client.get(sized_url(source));
+        if let Some(etag) = previous
+            .as_ref()
+            .and_then(|previous| previous.etag.as_ref())
+        {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+
+        let res = request.send()?;
+
+        let mirrored = match (res.status(), previous) {
+            (StatusCode::NOT_MODIFIED, Some(previous)) => {
+                debug!("Avatar for {} is unchanged", login);
+                MirroredAvatar {
+                    source: source.to_string(),
+                    ..previous
+                }
+            }
+            (status, previous) if status.is_success() => {
+                let etag = res
+                    .headers()
+                    .get(ETAG)
+                    .and_then(|value| value.to_str().ok())
+                    .map(|value| value.to_string());
+                let extension = match res
+                    .headers()
+                    .get(CONTENT_TYPE)
+                    .and_then(|value| value.to_str().ok())
+                {
+                    Some("image/jpeg") => "jpg",
+                    Some("image/gif") => "gif",
+                    Some("image/webp") => "webp",
+                    _ => "png",
+                };
+
+                // Database IDs are safe to use in paths unlike node IDs
+                let name = match user.database_id {
+                    Some(id) => id.to_string(),
+                    None => login.to_string(),
+                };
+                let file = format!("{}.{}", name, extension);
+                let bytes = res.bytes()?;
+                write_atomic(&self.dir.join(&file), &bytes)?;
+                debug!("Mirrored avatar for {}", login);
+
+                // Remove the previous file when the image type changed
+                if let Some(previous) = previous.filter(|previous| previous.file != file) {
+                    let _ = remove_file(self.dir.join(previous.file));
+                }
+
+                MirroredAvatar {
+                    source: source.to_string(),
+                    etag,
+                    file,
+                }
+            }
+            (status, _) => return Err(AvatarError::Status(status)),
+        };
+
+        let file = mirrored.file.clone();
+        self.manifest
+            .lock()
+            .expect("Avatar manifest lock poisoned")
+            .insert(key, mirrored);
+        Ok(file)
+    }
+}
+
+/// Removes the mirrored avatars of the provided users along with their
+/// manifest entries, providing the number of avatars removed
+///
+/// # Arguments
+/// * dir - The directory the avatars are stored in
+/// * keys - The node IDs or logins of the users to remove the avatars of
+/// * dry_run - Whether to only report the avatars that would be removed
+pub fn remove_avatars(dir: &Path, keys: &[Box<str>], dry_run: bool) -> Result<usize, AvatarError> {
+    let manifest_path = dir.join(MANIFEST_FILE);
+    let mut manifest: HashMap<String, MirroredAvatar> = match read_to_string(&manifest_path) {
+        Ok(json) => serde_json::from_str(&json).unwrap_or_default(),
+        Err(_) => return Ok(0),
+    };
+
+    let mut removed = 0;
+    for key in keys {
+        let Some(avatar) = manifest.remove(key.as_ref()) else {
+            continue;
+        };
+        info!("Removing mirrored avatar: {}", avatar.file);
+        if !dry_run {
+            let _ = remove_file(dir.join(avatar.file));
+        }
+        removed += 1;
+    }
+
+    if removed > 0 && !dry_run {
+        let json = serde_json::to_string_pretty(&manifest)?;
+        write_atomic(&manifest_path, json.as_bytes())?;
+    }
+    Ok(removed)
+}
+
+/// Checks whether the avatar URL has the u= hash of an uploaded avatar
+fn has_avatar_hash(url: &str) -> bool {
+    url.split_once('?')
+        .is_some_and(|(_, query)| query.split('&').any(|param| param.starts_with("u=")))
+}
+
+/// Adds the avatar size parameter to an avatar URL
+fn sized_url(url: &str) -> String {
+    let separator = if url.contains('?') { '&' } else { '?' };
+    format!("{}{}s={}", url, separator, AVATAR_SIZE)
+}
@@ -0,0 +1,101 @@
+use crate::export::escape_html;
+
+/// Height of the rendered badges
+const HEIGHT: u32 = 28;
+/// Horizontal padding on each side of the badge text
+const PADDING: f64 = 9.0;
+/// Extra spacing between each of the badge letters
+const LETTER_SPACING: f64 = 1.25;
+
+/// Background color of the badge label
+const LABEL_COLOR: &str = "#555";
+/// Background color of the message for ranked users
+pub const RANKED_COLOR: &str = "#3faf44";
+/// Background color of the message for users that aren't ranked
+pub const NOT_RANKED_COLOR: &str = "#da3333";
+
+/// Renders a "for-the-badge" style SVG badge
+///
+/// # Arguments
+/// * label - The text on the left side of the badge
+/// * message - The text on the right side of the badge
+/// * color - The background color of the message
+pub fn render_badge(label: &str, message: &str, color: &str) -> String {
+    let label = label.to_uppercase();
+    let message = message.to_uppercase();
+
+    let label_text = text_width(&label, false);
+    let message_text = text_width(&message, true);
+    let label_width = (label_text + PADDING * 2.0).round();
+    let message_width = (message_text + PADDING * 2.0).round();
+    let width = label_width + message_width;
+
+    let title = escape_html(&format!("{}: {}", label, message));
+    let label = escape_html(&label);
+    let message = escape_html(&message);
+
+    // Text is positioned at 10x scale for better precision
+    format!(
+        concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" role="img" aria-label="{title}">"#,
+            r#"<title>{title}</title>"#,
+            r#"<g shape-rendering="crispEdges">"#,
+            r#"<rect width="{label_width}" height="{height}" fill="{label_color}"/>"#,
+            r#"<rect x="{label_width}" width="{message_width}" height="{height}" fill="{color}"/>"#,
+            r#"</g>"#,
+            r##"<g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" text-rendering="geometricPrecision" font-size="100">"##,
+            r#"<text transform="scale(.1)" x="{label_x}" y="175" textLength="{label_length}">{label}</text>"#,
+            r#"<text transform="scale(.1)" x="{message_x}" y="175" font-weight="bold" textLength="{message_length}">{message}</text>"#,
+            r#"</g>"#,
+            r#"</svg>"#
+        ),
+        width = width,
+        height = HEIGHT,
+        title = title,
+        label_width = label_width,
+        message_width = message_width,
+        label_color = LABEL_COLOR,
+        color = color,
+        label_x = (label_width / 2.0 * 10.0).round(),
+        label_length = (label_text * 10.0).round(),
+        label = label,
+        message_x = ((label_width + message_width / 2.0) * 10.0).round(),
+        message_length = (message_text * 10.0).round(),
+        message = message,
+    )
+}
+
+/// Estimates the rendered width of text in 10px Verdana
+///
+/// # Arguments
+/// * text - The text to measure
+/// * bold - Whether the text is bold
+fn text_width(text: &str, bold: bool) -> f64 {
+    let width: f64 = text
+        .chars()
+        .map(|c| match c {
+            ' ' => 3.5,
+            '.' | ',' | ':' | '\'' => 3.6,
+            '-' => 4.3,
+            'I' => 4.2,
+            'J' => 4.6,
+            'L' => 5.6,
+            'M' => 8.6,
+            'W' => 9.9,
+            '#' => 8.4,
+            '0'..='9' => 6.4,
+            'A'..='Z' => 7.0,
+            // Wide characters such as CJK
+            c if c.len_utf8() > 2 => 10.0,
+            _ => 6.8,
+        })
+        .sum();
+
+    let spacing = LETTER_SPACING * text.chars().count().saturating_sub(1) as f64;
+    let width = width + spacing;
+    if bold {
+        width * 1.1
+    } else {
+        width
+    }
+}
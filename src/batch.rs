@@ -0,0 +1,500 @@
+use std::{collections::HashMap, fmt::Write};
+
+use graphql_client::{PathFragment, Response};
+use log::{debug, warn};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::{
+    client::GitHubClient,
+    limiter::RateLimiter,
+    search::{send, SearchError, MAX_ATTEMPTS},
+};
+
+/// Start of the error message GitHub gives for users that don't exist
+const NOT_FOUND_MESSAGE: &str = "Could not resolve to";
+
+/// Number of attempts for a batch of several users before it's split
+const BATCH_ATTEMPTS: usize = 2;
+
+/// Fragment on the User type selecting the fields fetched for each
+/// user within a batch
+pub struct UserFragment {
+    /// Name of the fragment
+    pub name: &'static str,
+    /// GraphQL document defining the fragment, the fragment and everything
+    /// after it in the document is included in batch queries
+    pub document: &'static str,
+    /// Estimated number of rate limit points spent for each user
+    pub cost: u32,
+}
+
+/// How the users within a batch are looked up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lookup {
+    /// By their current login
+    Login,
+    /// By their node ID, which still finds users that renamed
+    NodeId,
+}
+
+impl Lookup {
+    /// Provides the query field looking up a user by the key in the
+    /// provided variable along with the type of the variable
+    ///
+    /// # Arguments
+    /// * variable - The name of the variable holding the key
+    fn field(&self, variable: &str) -> (String, &'static str) {
+        match self {
+            Lookup::Login => (format!("user(login: ${})", variable), "String!"),
+            Lookup::NodeId => (format!("node(id: ${})", variable), "ID!"),
+        }
+    }
+}
+
+/// Users fetched by a batch fetcher
+pub struct Batched<T> {
+    /// Fetched fields by the key they were looked up with
+    pub users: HashMap<String, T>,
+    /// Keys of users that don't exist
+    pub missing: Vec<String>,
+    /// Keys of users that couldn't be fetched
+    pub failed: Vec<String>,
+}
+
+/// Fetches the fields of many users by aliasing a user lookup for each
+/// user within a single query. Failed batches are split and users whose
+/// lookups error are fetched again without the rest of the batch so that
+/// one troublesome user doesn't fail the whole batch
+pub struct BatchFetcher {
+    /// Maximum number of users in each request
+    batch_size: usize,
+    /// Maximum number of rate limit points spent by each request
+    max_cost: u32,
+}
+
+impl BatchFetcher {
+    /// Creates a new batch fetcher
+    ///
+    /// # Arguments
+    /// * batch_size - The maximum number of users in each request
+    /// * max_cost - The maximum number of rate limit points of each request
+    pub fn new(batch_size: usize, max_cost: u32) -> Self {
+        Self {
+            batch_size,
+            max_cost,
+        }
+    }
+
+    /// Provides the number of users in each request for a fragment,
+    /// limited by both the batch size and the cost of each request
+    ///
+    /// # Arguments
+    /// * fragment - The fragment fetched for each user
+    pub fn users_per_request(&self, fragment: &UserFragment) -> usize {
+        let by_cost = (self.max_cost / fragment.cost.max(1)) as usize;
+        self.batch_size.min(by_cost).max(1)
+    }
+
+    /// Estimates the number of requests and rate limit points needed to
+    /// fetch a fragment for the provided number of users
+    ///
+    /// # Arguments
+    /// * fragment - The fragment fetched for each user
+    /// * users - The number of users to fetch
+    pub fn estimate(&self, fragment: &UserFragment, users: usize) -> (usize, u32) {
+        let requests = users.div_ceil(self.users_per_request(fragment));
+        (requests, users as u32 * fragment.cost)
+    }
+
+    /// Fetches a fragment for each of the users with the provided keys
+    ///
+    /// # Arguments
+    /// * client - The client to make the graphql requests
+    /// * limiter - The shared rate limiter
+    /// * fragment - The fragment to fetch for each user
+    /// * lookup - How the users are looked up by their keys
+    /// * keys - The logins or node IDs of the users to fetch
+    pub fn fetch<T: DeserializeOwned>(
+        &self,
+        client: &GitHubClient,
+        limiter: &RateLimiter,
+        fragment: &UserFragment,
+        lookup: Lookup,
+        keys: &[String],
+    ) -> Batched<T> {
+        let mut batched = Batched {
+            users: HashMap::with_capacity(keys.len()),
+            missing: Vec::new(),
+            failed: Vec::new(),
+        };
+
+        let mut done = 0;
+        for batch in keys.chunks(self.users_per_request(fragment)) {
+            fetch_batch(fragment.name, batch, &mut batched, &|keys| {
+                request_batch(client, limiter, fragment, lookup, keys)
+            });
+            done += batch.len();
+            debug!("{} progress {}/{}", fragment.name, done, keys.len());
+        }
+
+        batched
+    }
+}
+
+/// Data of a batch response along with the aliases of the users
+/// whose lookups errored
+struct BatchData<T> {
+    /// Fetched fields by the alias of their user
+    users: HashMap<String, Option<T>>,
+    /// Aliases of the users whose lookups errored
+    errored: Vec<String>,
+}
+
+/// Requests a fragment for each of the users with the provided keys,
+/// batches are retried once before failing so that they can be split
+/// while single users are retried the full number of times
+///
+/// # Arguments
+/// * client - The client to make the graphql requests
+/// * limiter - The shared rate limiter
+/// * fragment - The fragment to fetch for each user
+/// * lookup - How the users are looked up by their keys
+/// * keys - The keys of the users in the batch
+fn request_batch<T: DeserializeOwned>(
+    client: &GitHubClient,
+    limiter: &RateLimiter,
+    fragment: &UserFragment,
+    lookup: Lookup,
+    keys: &[String],
+) -> Result<BatchData<T>, SearchError> {
+    let query = batch_query(fragment, lookup, keys.len());
+    let variables: Map<String, Value> = keys
+        .iter()
+        .enumerate()
+        .map(|(index, key)| (format!("key{}", index), Value::from(key.as_str())))
+        .collect();
+    let variables = Value::Object(variables);
+    let cost = fragment.cost * keys.len() as u32;
+    let attempts = if keys.len() > 1 {
+        BATCH_ATTEMPTS
+    } else {
+        MAX_ATTEMPTS
+    };
+
+    send(limiter, cost, attempts, || {
+        client
+            .query_raw::<HashMap<String, Option<T>>>(&query, &variables)
+            .map(separate_user_errors)
+    })
+}
+
+/// Fetches a single batch of users. Batches whose request fails are
+/// split in half, such as when a large query times out, and users whose
+/// lookups errored are fetched again without the rest of the batch
+///
+/// # Arguments
+/// * name - The name of the fragment being fetched
+/// * keys - The keys of the users in the batch
+/// * batched - The fetched users to add to
+/// * request - Function requesting the users with the provided keys
+fn fetch_batch<T, F>(name: &str, keys: &[String], batched: &mut Batched<T>, request: &F)
+where
+    F: Fn(&[String]) -> Result<BatchData<T>, SearchError>,
+{
+    let BatchData { mut users, errored } = match request(keys) {
+        Ok(value) => value,
+        Err(err) if keys.len() > 1 => {
+            warn!("Batch of {} users failed, splitting: {}", keys.len(), err);
+            let (first, second) = keys.split_at(keys.len() / 2);
+            fetch_batch(name, first, batched, request);
+            fetch_batch(name, second, batched, request);
+            return;
+        }
+        Err(err) => {
+            warn!("Failed to fetch {} for {}: {}", name, keys[0], err);
+            batched.failed.extend_from_slice(keys);
+            return;
+        }
+    };
+
+    let mut retry = Vec::new();
+    for (index, key) in keys.iter().enumerate() {
+        let alias = format!("user{}", index);
+        if errored.contains(&alias) {
+            retry.push(key.clone());
+            continue;
+        }
+
+        match users.remove(&alias).flatten() {
+            Some(user) => {
+                batched.users.insert(key.clone(), user);
+            }
+            None => {
+                warn!("User {} doesn't exist", key);
+                batched.missing.push(key.clone());
+            }
+        }
+    }
+
+    match retry.as_slice() {
+        [] => {}
+        [key] if keys.len() == 1 => {
+            warn!("Failed to fetch {} for {}", name, key);
+            batched.failed.push(key.clone());
+        }
+        // Every user errored so the batch is split to find the problem users
+        _ if retry.len() == keys.len() => {
+            warn!("Every user in a batch of {} errored, splitting", keys.len());
+            let (first, second) = keys.split_at(keys.len() / 2);
+            fetch_batch(name, first, batched, request);
+            fetch_batch(name, second, batched, request);
+        }
+        _ => {
+            warn!("{} users in a batch errored, fetching again", retry.len());
+            fetch_batch(name, &retry, batched, request);
+        }
+    }
+}
+
+/// Builds a query fetching a fragment for several users, each user is
+/// aliased as user{index} with their key in the key{index} variable
+///
+/// # Arguments
+/// * fragment - The fragment to fetch for each user
+/// * lookup - How the users are looked up by their keys
+/// * count - The number of users to fetch
+fn batch_query(fragment: &UserFragment, lookup: Lookup, count: usize) -> String {
+    let definition = &fragment.document[fragment
+        .document
+        .find(&format!("fragment {} ", fragment.name))
+        .expect("Fragment document should define the fragment")..];
+
+    let mut variables = Vec::with_capacity(count);
+    let mut fields = Vec::with_capacity(count);
+    for index in 0..count {
+        let (field, variable_type) = lookup.field(&format!("key{}", index));
+        variables.push(format!("$key{}: {}", index, variable_type));
+        fields.push(field);
+    }
+
+    let mut query = format!(
+        "query Batch{}({}) {{\n",
+        fragment.name,
+        variables.join(", ")
+    );
+    for (index, field) in fields.iter().enumerate() {
+        let _ = writeln!(
+            query,
+            "  user{}: {} {{\n    ...{}\n  }}",
+            index, field, fragment.name
+        );
+    }
+    query.push_str("}\n\n");
+    query.push_str(definition);
+    query
+}
+
+/// Separates the errors of individual users from a batch response so
+/// that only errors affecting the whole batch cause it to be retried.
+/// Users that don't exist are null in the data and aren't errored
+fn separate_user_errors<T>(res: Response<HashMap<String, Option<T>>>) -> Response<BatchData<T>> {
+    let Response {
+        data,
+        errors,
+        extensions,
+    } = res;
+
+    let Some(users) = data else {
+        return Response {
+            data: None,
+            errors,
+            extensions,
+        };
+    };
+
+    let mut errored = Vec::new();
+    let mut remaining = Vec::new();
+    for error in errors.into_iter().flatten() {
+        match error.path.as_ref().and_then(|path| path.first()) {
+            Some(PathFragment::Key(alias)) if users.contains_key(alias) => {
+                if !error.message.starts_with(NOT_FOUND_MESSAGE) {
+                    errored.push(alias.clone());
+                }
+            }
+            _ => remaining.push(error),
+        }
+    }
+
+    Response {
+        data: Some(BatchData { users, errored }),
+        errors: (!remaining.is_empty()).then_some(remaining),
+        extensions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use graphql_client::Error;
+
+    use super::*;
+
+    fn error(message: &str, alias: Option<&str>) -> Error {
+        Error {
+            message: message.to_string(),
+            locations: None,
+            path: alias.map(|alias| vec![PathFragment::Key(alias.to_string())]),
+            extensions: None,
+        }
+    }
+
+    fn keys(keys: &[&str]) -> Vec<String> {
+        keys.iter().map(|key| key.to_string()).collect()
+    }
+
+    fn batched() -> Batched<String> {
+        Batched {
+            users: HashMap::new(),
+            missing: Vec::new(),
+            failed: Vec::new(),
+        }
+    }
+
+    /// Responds to a batch with each user's key as their data, users
+    /// named "missing" don't exist
+    fn respond(keys: &[String], errored: &[&str]) -> BatchData<String> {
+        let mut data = BatchData {
+            users: HashMap::new(),
+            errored: Vec::new(),
+        };
+        for (index, key) in keys.iter().enumerate() {
+            let alias = format!("user{}", index);
+            if errored.contains(&key.as_str()) {
+                data.errored.push(alias.clone());
+            }
+            let user = (key != "missing").then(|| key.clone());
+            data.users.insert(alias, user);
+        }
+        data
+    }
+
+    #[test]
+    fn separate_user_errors_keeps_batch_errors() {
+        let users = HashMap::from([
+            ("user0".to_string(), None),
+            ("user1".to_string(), None),
+            ("user2".to_string(), Some(2)),
+        ]);
+        let res = separate_user_errors(Response {
+            data: Some(users),
+            errors: Some(vec![
+                error("Something went wrong", Some("user0")),
+                error(
+                    "Could not resolve to a User with the login of 'x'.",
+                    Some("user1"),
+                ),
+                error("Timeout on validation of query", None),
+            ]),
+            extensions: None,
+        });
+
+        let data = res.data.unwrap();
+        assert_eq!(data.errored, ["user0"]);
+        assert_eq!(data.users["user2"], Some(2));
+        let errors = res.errors.unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "Timeout on validation of query");
+    }
+
+    #[test]
+    fn separate_user_errors_without_user_errors() {
+        let res = separate_user_errors(Response {
+            data: Some(HashMap::from([("user0".to_string(), Some(0))])),
+            errors: Some(vec![error(
+                "Could not resolve to a User with the login of 'x'.",
+                Some("user0"),
+            )]),
+            extensions: None,
+        });
+        assert!(res.errors.is_none());
+        assert!(res.data.unwrap().errored.is_empty());
+
+        let res = separate_user_errors::<u32>(Response {
+            data: None,
+            errors: Some(vec![error("Something went wrong", Some("user0"))]),
+            extensions: None,
+        });
+        assert!(res.data.is_none());
+        assert_eq!(res.errors.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn fetch_batch_splits_failed_requests() {
+        let requests = RefCell::new(0);
+        let mut batched = batched();
+        fetch_batch(
+            "Test",
+            &keys(&["a", "b", "c", "d", "missing"]),
+            &mut batched,
+            &|keys: &[String]| {
+                *requests.borrow_mut() += 1;
+                // Large batches time out
+                if keys.len() > 2 {
+                    return Err(SearchError::MissingData);
+                }
+                Ok(respond(keys, &[]))
+            },
+        );
+
+        assert_eq!(batched.users.len(), 4);
+        assert_eq!(batched.missing, ["missing"]);
+        assert!(batched.failed.is_empty());
+        assert_eq!(*requests.borrow(), 5);
+    }
+
+    #[test]
+    fn fetch_batch_fails_only_the_problem_user() {
+        let mut batched = batched();
+        fetch_batch(
+            "Test",
+            &keys(&["a", "bad", "c", "d"]),
+            &mut batched,
+            &|keys: &[String]| {
+                if keys.iter().any(|key| key == "bad") {
+                    return Err(SearchError::RequestErrors);
+                }
+                Ok(respond(keys, &[]))
+            },
+        );
+
+        assert_eq!(batched.users.len(), 3);
+        assert_eq!(batched.failed, ["bad"]);
+    }
+
+    #[test]
+    fn fetch_batch_refetches_only_errored_users() {
+        let requests = RefCell::new(Vec::new());
+        let mut batched = batched();
+        fetch_batch(
+            "Test",
+            &keys(&["a", "flaky", "broken", "d"]),
+            &mut batched,
+            &|keys: &[String]| {
+                requests.borrow_mut().push(keys.len());
+                let errored = if keys.len() > 2 {
+                    &["flaky", "broken"][..]
+                } else {
+                    &["broken"][..]
+                };
+                Ok(respond(keys, errored))
+            },
+        );
+
+        assert_eq!(batched.users.len(), 3);
+        assert_eq!(batched.users["flaky"], "flaky");
+        assert_eq!(batched.failed, ["broken"]);
+        assert_eq!(*requests.borrow(), [4, 2, 1]);
+    }
+}
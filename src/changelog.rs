@@ -0,0 +1,142 @@
+use std::fmt::Write;
+
+use crate::{
+    diff::{diff_outputs, Ranks},
+    output::Output,
+};
+
+/// Board sizes that newcomers are reported for
+const TOP_SIZES: &[usize] = &[10, 100];
+
+/// Commit counts that are reported when a user passes them
+const MILESTONES: &[i64] = &[1000, 2500, 5000, 10000, 25000, 50000];
+
+/// Maximum number of climbers to report
+const CLIMBERS: usize = 10;
+
+/// Renders a markdown changelog summarizing the changes between
+/// the previous and current snapshots of a preset
+///
+/// # Arguments
+/// * previous - The previous snapshot
+/// * current - The current snapshot
+pub fn render_changelog(previous: &Output, current: &Output) -> String {
+    let diff = diff_outputs(previous, current, &[], usize::MAX);
+    let previous_ranks = Ranks::of(previous);
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "# {} changelog for {}\n",
+        current.title,
+        current.generated_at.date_naive()
+    );
+    let _ = writeln!(
+        out,
+        "- **Total commits:** {} ({:+} since {})",
+        diff.total_commits.new,
+        diff.total_commits.new - diff.total_commits.old,
+        previous.generated_at.date_naive()
+    );
+    let _ = writeln!(out, "- **Users on the board:** {}", current.users.len());
+    let _ = writeln!(
+        out,
+        "- **Entered / left the board:** {} / {}",
+        diff.entered.len(),
+        diff.left.len()
+    );
+
+    for &size in TOP_SIZES {
+        let newcomers: Vec<String> = current
+            .users
+            .iter()
+            .take(size)
+            .enumerate()
+            .filter_map(|(index, user)| {
+                let previously = match previous_ranks.get(user) {
+                    Some((rank, _)) if rank <= size => return None,
+                    Some((rank, _)) => format!("previously #{}", rank),
+                    None => "new to the board".to_string(),
+                };
+                Some(format!(
+                    "- #{} {} with {} commits ({})",
+                    index + 1,
+                    user_link(&user.login),
+                    user.commits,
+                    previously
+                ))
+            })
+            .collect();
+
+        if !newcomers.is_empty() {
+            let _ = writeln!(out, "\n## Newcomers to the top {}\n", size);
+            let _ = writeln!(out, "{}", newcomers.join("\n"));
+        }
+    }
+
+    let climbers: Vec<String> = diff
+        .movers
+        .iter()
+        .filter(|mover| mover.change > 0)
+        .take(CLIMBERS)
+        .map(|mover| {
+            format!(
+                "- {} climbed {} places to #{}",
+                user_link(&mover.login),
+                mover.change,
+                mover.new_rank
+            )
+        })
+        .collect();
+
+    if !climbers.is_empty() {
+        let _ = writeln!(out, "\n## Biggest climbers\n");
+        let _ = writeln!(out, "{}", climbers.join("\n"));
+    }
+
+    let renames: Vec<String> = diff
+        .renamed
+        .iter()
+        .map(|rename| {
+            format!(
+                "- {} is now {}",
+                rename.old_login,
+                user_link(&rename.new_login)
+            )
+        })
+        .collect();
+
+    if !renames.is_empty() {
+        let _ = writeln!(out, "\n## Renamed\n");
+        let _ = writeln!(out, "{}", renames.join("\n"));
+    }
+
+    let milestones: Vec<String> = current
+        .users
+        .iter()
+        .filter_map(|user| {
+            let (_, previous_user) = previous_ranks.get(user)?;
+            let milestone = MILESTONES.iter().rev().find(|&&milestone| {
+                previous_user.commits < milestone && user.commits >= milestone
+            })?;
+            Some(format!(
+                "- {} passed {} commits (now {})",
+                user_link(&user.login),
+                milestone,
+                user.commits
+            ))
+        })
+        .collect();
+
+    if !milestones.is_empty() {
+        let _ = writeln!(out, "\n## Milestones\n");
+        let _ = writeln!(out, "{}", milestones.join("\n"));
+    }
+
+    out
+}
+
+/// Creates a markdown link to the GitHub profile of a user
+fn user_link(login: &str) -> String {
+    format!("[{login}](https://github.com/{login})")
+}
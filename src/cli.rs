@@ -0,0 +1,294 @@
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand};
+
+use crate::{
+    diff::DiffFormat, export::ExportFormat, output::Metric, privacy::PrivateContribs, VERSION,
+};
+
+/// Tracker that takes snapshots of which GitHub users in
+/// a location have made the most commits
+#[derive(Debug, Parser)]
+#[command(name = "committers-loader", version = VERSION)]
+pub struct Cli {
+    /// Directory the output data is stored in
+    #[arg(
+        long,
+        global = true,
+        env = "COMMITTERS_DATA_DIR",
+        default_value = "data"
+    )]
+    pub data_dir: PathBuf,
+
+    /// Path to the file containing blacklisted usernames
+    #[arg(
+        long,
+        global = true,
+        env = "COMMITTERS_BLACKLIST",
+        default_value = "blacklist.txt"
+    )]
+    pub blacklist: PathBuf,
+
+    /// Path to the file containing the usernames of users that asked
+    /// not to be listed
+    #[arg(
+        long,
+        global = true,
+        env = "COMMITTERS_OPTOUT",
+        default_value = "optout.txt"
+    )]
+    pub optout: PathBuf,
+
+    /// Path to the file containing the usernames of users whose private
+    /// contributions are always hidden
+    #[arg(
+        long,
+        global = true,
+        env = "COMMITTERS_PRIVATE_LIST",
+        default_value = "private.txt"
+    )]
+    pub private_list: PathBuf,
+
+    /// How the private contributions of users are included in the output
+    #[arg(
+        long,
+        global = true,
+        env = "COMMITTERS_PRIVATE_CONTRIBS",
+        value_enum,
+        default_value_t = PrivateContribs::Exact
+    )]
+    pub private_contribs: PrivateContribs,
+
+    /// Path of the output file for the default preset, can be repeated
+    /// to write to several destinations with the first being read from
+    /// [default: <DATA_DIR>/output.json]
+    #[arg(long, global = true, env = "COMMITTERS_OUTPUT", value_delimiter = ',')]
+    pub output: Vec<PathBuf>,
+
+    /// Directory mirrored avatars are stored in, this should be a directory
+    /// the site serves under the avatar prefix
+    /// [default: <DATA_DIR>/avatars]
+    #[arg(long, global = true, env = "COMMITTERS_AVATAR_DIR")]
+    pub avatar_dir: Option<PathBuf>,
+
+    /// Report what would be done without writing any files, crawls print
+    /// their queries and estimated cost without collecting users
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Crawl GitHub for the top committers of location presets
+    Crawl(CrawlArgs),
+    /// Update the users in the stored snapshots of location presets
+    /// without searching for new users
+    Refresh(RefreshArgs),
+    /// Remove blacklisted users from every stored snapshot and apply
+    /// the private contribution settings
+    Blacklist,
+    /// Remove opted out users from every stored snapshot, changelog,
+    /// badge and mirrored avatar
+    Optout,
+    /// Inspect the available location presets
+    #[command(subcommand)]
+    Presets(PresetsCommand),
+    /// Check the current output for problems
+    Validate,
+    /// Print the JSON Schema describing the output format
+    Schema(SchemaArgs),
+    /// Export the leaderboard of a stored snapshot
+    Export(ExportArgs),
+    /// Render SVG rank badges for every ranked user
+    Badges(BadgesArgs),
+    /// Compare two stored snapshots
+    Diff(DiffArgs),
+    /// Render the markdown changelog between two stored snapshots
+    Changelog(ChangelogArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct ChangelogArgs {
+    /// The previous snapshot
+    pub previous: PathBuf,
+
+    /// The current snapshot [default: the output file]
+    pub current: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct DiffArgs {
+    /// The older snapshot
+    pub old: PathBuf,
+
+    /// The newer snapshot
+    pub new: PathBuf,
+
+    /// Format to print the differences as
+    #[arg(long, short, value_enum, default_value_t = DiffFormat::Text)]
+    pub format: DiffFormat,
+
+    /// Maximum number of rank movers and commit changes to show
+    #[arg(long, default_value_t = 10)]
+    pub limit: usize,
+}
+
+#[derive(Debug, Args)]
+pub struct BadgesArgs {
+    /// Metric to rank users by (Can be repeated)
+    #[arg(long, short, value_enum, default_values_t = [Metric::Commits])]
+    pub metric: Vec<Metric>,
+
+    /// Title or slug of a preset to render badges for (Can be repeated)
+    /// [default: every preset with a stored snapshot]
+    #[arg(long, short)]
+    pub preset: Vec<String>,
+
+    /// Directory to write the badges to [default: <DATA_DIR>/badges]
+    #[arg(long)]
+    pub out: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct ExportArgs {
+    /// Format to export the leaderboard as
+    #[arg(long, short, value_enum, default_value_t = ExportFormat::Markdown)]
+    pub format: ExportFormat,
+
+    /// Snapshot to export [default: the output file]
+    #[arg(long, short)]
+    pub input: Option<PathBuf>,
+
+    /// Write the export to a file instead of printing it
+    #[arg(long)]
+    pub out: Option<PathBuf>,
+
+    /// Maximum number of users to include
+    #[arg(long)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Args)]
+pub struct SchemaArgs {
+    /// Write the schema to a file instead of printing it
+    #[arg(long)]
+    pub out: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct CrawlArgs {
+    #[command(flatten)]
+    pub presets: PresetArgs,
+
+    /// Number of presets to crawl at the same time
+    #[arg(long, default_value_t = 4)]
+    pub workers: usize,
+
+    #[command(flatten)]
+    pub batch: BatchArgs,
+
+    #[command(flatten)]
+    pub enrich: EnrichArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct RefreshArgs {
+    #[command(flatten)]
+    pub presets: PresetArgs,
+
+    /// Number of presets to refresh at the same time
+    #[arg(long, default_value_t = 4)]
+    pub workers: usize,
+
+    #[command(flatten)]
+    pub batch: BatchArgs,
+
+    #[command(flatten)]
+    pub enrich: EnrichArgs,
+}
+
+/// Options for selecting the presets to collect
+#[derive(Debug, Args)]
+pub struct PresetArgs {
+    /// Use every known preset
+    #[arg(long, conflicts_with = "preset")]
+    pub all: bool,
+
+    /// Title or slug of a preset to use (Can be repeated)
+    /// [default: the default preset]
+    #[arg(long, short)]
+    pub preset: Vec<String>,
+}
+
+/// Options for the extra details collected for each user
+#[derive(Debug, Args)]
+pub struct EnrichArgs {
+    /// Fetch the top languages of each user (Fetched in batches of users)
+    #[arg(long)]
+    pub languages: bool,
+
+    /// Download the avatars of users into the avatar directory and link
+    /// to them instead of GitHub
+    #[arg(long)]
+    pub mirror_avatars: bool,
+
+    /// Prefix of the paths mirrored avatars are linked with
+    #[arg(long, default_value = "/avatars/", requires = "mirror_avatars")]
+    pub avatar_prefix: String,
+}
+
+/// Options for the requests fetching the details of many users at once
+#[derive(Debug, Args)]
+pub struct BatchArgs {
+    /// Maximum number of users fetched in each batched request
+    #[arg(long, default_value_t = 25)]
+    pub batch_size: usize,
+
+    /// Maximum number of rate limit points spent by each batched request
+    #[arg(long, default_value_t = 100)]
+    pub batch_cost: u32,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum PresetsCommand {
+    /// List the available presets
+    List,
+    /// Check every preset against the GitHub search query limits
+    Validate,
+}
+
+impl Cli {
+    /// Provides the paths of the output files for the default preset
+    pub fn output_paths(&self) -> Vec<PathBuf> {
+        if self.output.is_empty() {
+            vec![self.data_dir.join("output.json")]
+        } else {
+            self.output.clone()
+        }
+    }
+
+    /// Provides the path of the output file that existing output
+    /// for the default preset is read from
+    pub fn output_path(&self) -> PathBuf {
+        self.output
+            .first()
+            .cloned()
+            .unwrap_or_else(|| self.data_dir.join("output.json"))
+    }
+
+    /// Provides the directory mirrored avatars are stored in
+    pub fn avatar_dir(&self) -> PathBuf {
+        self.avatar_dir
+            .clone()
+            .unwrap_or_else(|| self.data_dir.join("avatars"))
+    }
+
+    /// Provides the path of the file storing the node IDs that logins
+    /// in the list files resolved to
+    pub fn identities_path(&self) -> PathBuf {
+        self.data_dir.join("identities.json")
+    }
+}
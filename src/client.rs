@@ -0,0 +1,277 @@
+use std::{
+    env::var,
+    fs::read_to_string,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread::sleep,
+    time::Duration,
+};
+
+use chrono::prelude::*;
+use graphql_client::{GraphQLQuery, Response};
+use log::{debug, warn};
+use reqwest::{
+    blocking::Client,
+    header::{HeaderMap, HeaderValue, InvalidHeaderValue, AUTHORIZATION},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+use thiserror::Error;
+
+use crate::VERSION;
+
+/// GitHub API URL for GraphQL
+const GRAPHQL_URL: &str = "https://api.github.com/graphql";
+
+/// Header containing the number of points remaining for the token
+const RATE_LIMIT_REMAINING: &str = "x-ratelimit-remaining";
+/// Header containing the unix timestamp the token budget resets at
+const RATE_LIMIT_RESET: &str = "x-ratelimit-reset";
+
+/// Errors that could occur while creating the client
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("GITHUB_TOKEN not set (Set GITHUB_TOKENS, GITHUB_TOKEN_FILE or GITHUB_TOKEN)")]
+    MissingTokens,
+    #[error("Failed to read token file: {0}")]
+    ReadTokenFile(#[from] std::io::Error),
+    #[error("Invalid token header: {0}")]
+    InvalidTokenHeader(#[from] InvalidHeaderValue),
+    #[error("Failed to create request client: {0}")]
+    Build(#[from] reqwest::Error),
+}
+
+/// GitHub GraphQL client which rotates requests between multiple
+/// tokens, skipping tokens that have exhausted their rate limit
+/// until they are reset
+pub struct GitHubClient {
+    /// The clients for each of the tokens
+    tokens: Vec<TokenClient>,
+    /// Index of the next token to use
+    next: AtomicUsize,
+}
+
+/// Client for an individual token along with its rate limit state
+struct TokenClient {
+    /// Index of the token (Used for logging rather than the token itself)
+    index: usize,
+    /// Client with the token authorization header
+    client: Client,
+    /// The last known rate limit state
+    state: Mutex<TokenState>,
+}
+
+#[derive(Default)]
+struct TokenState {
+    /// Number of points remaining for the token
+    remaining: Option<u32>,
+    /// When the rate limit for the token will be reset
+    reset_at: Option<DateTime<Utc>>,
+}
+
+impl TokenState {
+    /// Checks whether the token can be used at the provided time
+    fn is_available(&self, now: DateTime<Utc>) -> bool {
+        match (self.remaining, self.reset_at) {
+            (Some(0), Some(reset_at)) => reset_at <= now,
+            _ => true,
+        }
+    }
+}
+
+/// Loads the collection of GitHub tokens from the environment, tokens
+/// are collected from the comma separated GITHUB_TOKENS variable, the
+/// file at GITHUB_TOKEN_FILE (One token per line) and GITHUB_TOKEN
+pub fn load_tokens() -> Result<Vec<String>, ClientError> {
+    let mut tokens: Vec<String> = Vec::new();
+
+    if let Ok(value) = var("GITHUB_TOKENS") {
+        tokens.extend(value.split(',').map(|token| token.trim().to_string()));
+    }
+
+    if let Ok(path) = var("GITHUB_TOKEN_FILE") {
+        let file = read_to_string(path)?;
+        tokens.extend(
+            file.lines()
+                .map(|line| line.trim())
+                .filter(|line| !line.starts_with('#'))
+                .map(|line| line.to_string()),
+        );
+    }
+
+    if let Ok(value) = var("GITHUB_TOKEN") {
+        tokens.push(value.trim().to_string());
+    }
+
+    tokens.retain(|token| !token.is_empty());
+
+    // Remove duplicate tokens while keeping their order
+    let mut index = 0;
+    while index < tokens.len() {
+        if tokens[..index].contains(&tokens[index]) {
+            tokens.remove(index);
+        } else {
+            index += 1;
+        }
+    }
+
+    Ok(tokens)
+}
+
+impl GitHubClient {
+    /// Creates a new client for the provided tokens
+    ///
+    /// # Arguments
+    /// * tokens - The GitHub tokens to rotate between
+    pub fn new(tokens: Vec<String>) -> Result<Self, ClientError> {
+        if tokens.is_empty() {
+            return Err(ClientError::MissingTokens);
+        }
+
+        let tokens = tokens
+            .into_iter()
+            .enumerate()
+            .map(|(index, token)| {
+                let mut headers = HeaderMap::with_capacity(1);
+                headers.insert(
+                    AUTHORIZATION,
+                    HeaderValue::from_str(&format!("Bearer {}", token))?,
+                );
+
+                let client = Client::builder()
+                    .user_agent(format!("Committer/{}", VERSION))
+                    .default_headers(headers)
+                    .build()?;
+
+                Ok(TokenClient {
+                    index,
+                    client,
+                    state: Mutex::new(TokenState::default()),
+                })
+            })
+            .collect::<Result<Vec<_>, ClientError>>()?;
+
+        Ok(Self {
+            tokens,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// The number of tokens the client rotates between
+    pub fn token_count(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// Selects the next token that hasn't exhausted its rate limit,
+    /// blocking until the earliest reset if all tokens are exhausted
+    fn select(&self) -> &TokenClient {
+        loop {
+            let start = self.next.fetch_add(1, Ordering::Relaxed);
+            let now = Utc::now();
+            let mut earliest_reset: Option<DateTime<Utc>> = None;
+
+            for offset in 0..self.tokens.len() {
+                let token = &self.tokens[(start + offset) % self.tokens.len()];
+                let state = token.state.lock().expect("Token state lock poisoned");
+                if state.is_available(now) {
+                    return token;
+                }
+
+                if let Some(reset_at) = state.reset_at {
+                    earliest_reset = Some(match earliest_reset {
+                        Some(earliest) => earliest.min(reset_at),
+                        None => reset_at,
+                    });
+                }
+            }
+
+            let wait = earliest_reset
+                .and_then(|reset_at| (reset_at - now).to_std().ok())
+                .unwrap_or(Duration::from_secs(60))
+                // Allow a little extra time for the reset to apply
+                + Duration::from_secs(1);
+
+            warn!("All tokens exhausted their rate limit, waiting {:?}", wait);
+            sleep(wait);
+        }
+    }
+
+    /// Posts a derived GraphQL query using the next available token
+    ///
+    /// # Arguments
+    /// * variables - The variables for the query
+    pub fn query<Q: GraphQLQuery>(
+        &self,
+        variables: Q::Variables,
+    ) -> Result<Response<Q::ResponseData>, reqwest::Error> {
+        self.post(&Q::build_query(variables))
+    }
+
+    /// Posts a GraphQL query that is built at runtime rather than
+    /// derived, such as queries with a varying number of aliases
+    ///
+    /// # Arguments
+    /// * query - The GraphQL query document
+    /// * variables - The variables for the query
+    pub fn query_raw<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: &Value,
+    ) -> Result<Response<T>, reqwest::Error> {
+        self.post(&json!({
+            "query": query,
+            "variables": variables,
+        }))
+    }
+
+    /// Posts a GraphQL request body using the next available token
+    ///
+    /// # Arguments
+    /// * body - The request body
+    fn post<B: Serialize, T: DeserializeOwned>(
+        &self,
+        body: &B,
+    ) -> Result<Response<T>, reqwest::Error> {
+        let token = self.select();
+        let response = token.client.post(GRAPHQL_URL).json(body).send()?;
+        token.update(response.headers());
+        response.json()
+    }
+}
+
+impl TokenClient {
+    /// Updates the rate limit state of the token from the
+    /// rate limit headers of a response
+    ///
+    /// # Arguments
+    /// * headers - The response headers
+    fn update(&self, headers: &HeaderMap) {
+        fn header<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+            headers.get(name)?.to_str().ok()?.parse().ok()
+        }
+
+        let remaining: Option<u32> = header(headers, RATE_LIMIT_REMAINING);
+        let reset_at = header::<i64>(headers, RATE_LIMIT_RESET)
+            .and_then(|value| Utc.timestamp_opt(value, 0).single());
+
+        let mut state = self.state.lock().expect("Token state lock poisoned");
+        if remaining.is_some() {
+            state.remaining = remaining;
+        }
+        if reset_at.is_some() {
+            state.reset_at = reset_at;
+        }
+
+        if let Some(remaining) = state.remaining {
+            debug!("Token #{} has {} points remaining", self.index, remaining);
+            if remaining == 0 {
+                warn!(
+                    "Token #{} exhausted its rate limit until {:?}",
+                    self.index, state.reset_at
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,325 @@
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+use chrono::prelude::*;
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::output::{Output, User};
+
+/// Formats that a diff can be printed as
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum DiffFormat {
+    Text,
+    Json,
+}
+
+/// Differences between two snapshots of the same preset
+#[derive(Debug, Serialize)]
+pub struct SnapshotDiff {
+    /// When the old and new snapshots were generated
+    pub generated_at: Change<DateTime<Utc>>,
+    /// Number of followers required to be included
+    pub min_followers: Change<i64>,
+    /// Number of users on the board
+    pub users: Change<usize>,
+    /// Total number of commits across the board
+    pub total_commits: Change<i64>,
+    /// Users that entered the board
+    pub entered: Vec<RankedUser>,
+    /// Users that left the board
+    pub left: Vec<RankedUser>,
+    /// Users that left the board because they are now blacklisted
+    pub blacklisted: Vec<RankedUser>,
+    /// Users that changed their login
+    pub renamed: Vec<Rename>,
+    /// Users with the biggest rank changes, largest first
+    pub movers: Vec<RankChange>,
+    /// Users with the biggest commit changes, largest first
+    pub commit_deltas: Vec<CommitChange>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Change<T> {
+    pub old: T,
+    pub new: T,
+}
+
+impl<T: PartialEq> Change<T> {
+    pub fn is_changed(&self) -> bool {
+        self.old != self.new
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct RankedUser {
+    pub login: String,
+    pub rank: usize,
+    pub commits: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Rename {
+    pub old_login: String,
+    pub new_login: String,
+    pub rank: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RankChange {
+    pub login: String,
+    pub old_rank: usize,
+    pub new_rank: usize,
+    /// Number of places moved, positive when the user climbed
+    pub change: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CommitChange {
+    pub login: String,
+    pub old_commits: i64,
+    pub new_commits: i64,
+    pub delta: i64,
+}
+
+impl RankedUser {
+    fn new(rank: usize, user: &User) -> Self {
+        Self {
+            login: user.login.clone(),
+            rank,
+            commits: user.commits,
+        }
+    }
+}
+
+/// Rank and user of each user in an output, looked up by account
+/// so that users who renamed are still found by their node ID
+pub struct Ranks<'a> {
+    by_id: HashMap<&'a str, (usize, &'a User)>,
+    by_login: HashMap<&'a str, (usize, &'a User)>,
+}
+
+impl<'a> Ranks<'a> {
+    /// Maps each user in the output to their rank and user
+    pub fn of(output: &'a Output) -> Self {
+        let ranked = output
+            .users
+            .iter()
+            .enumerate()
+            .map(|(index, user)| (index + 1, user));
+        Self {
+            by_id: ranked
+                .clone()
+                .filter_map(|(rank, user)| Some((user.id.as_deref()?, (rank, user))))
+                .collect(),
+            by_login: ranked
+                .map(|(rank, user)| (user.login.as_str(), (rank, user)))
+                .collect(),
+        }
+    }
+
+    /// Provides the rank and user of the same account as the provided user
+    ///
+    /// # Arguments
+    /// * user - The user to find
+    pub fn get(&self, user: &User) -> Option<(usize, &'a User)> {
+        if let Some(found) = user.id.as_deref().and_then(|id| self.by_id.get(id)) {
+            return Some(*found);
+        }
+
+        // Users without IDs from older snapshots are matched by login
+        self.by_login
+            .get(user.login.as_str())
+            .filter(|(_, other)| other.is_same_account(user))
+            .copied()
+    }
+}
+
+/// Compares two snapshots of the same preset
+///
+/// # Arguments
+/// * old - The older snapshot
+/// * new - The newer snapshot
+/// * blacklist - List of blacklisted names
+/// * limit - Maximum number of movers and commit changes to include
+pub fn diff_outputs(
+    old: &Output,
+    new: &Output,
+    blacklist: &[Box<str>],
+    limit: usize,
+) -> SnapshotDiff {
+    let old_ranks = Ranks::of(old);
+    let new_ranks = Ranks::of(new);
+
+    let entered = new
+        .users
+        .iter()
+        .enumerate()
+        .filter(|(_, user)| old_ranks.get(user).is_none())
+        .map(|(index, user)| RankedUser::new(index + 1, user))
+        .collect();
+
+    let (blacklisted, left): (Vec<_>, Vec<_>) = old
+        .users
+        .iter()
+        .enumerate()
+        .filter(|(_, user)| new_ranks.get(user).is_none())
+        .partition(|(_, user)| user.is_listed(blacklist));
+    let ranked = |users: Vec<(usize, &User)>| {
+        users
+            .into_iter()
+            .map(|(index, user)| RankedUser::new(index + 1, user))
+            .collect()
+    };
+
+    let mut renamed = Vec::new();
+    let mut movers = Vec::new();
+    let mut commit_deltas = Vec::new();
+
+    for (new_index, user) in new.users.iter().enumerate() {
+        let Some((old_rank, old_user)) = old_ranks.get(user) else {
+            continue;
+        };
+        let new_rank = new_index + 1;
+
+        if old_user.login != user.login {
+            renamed.push(Rename {
+                old_login: old_user.login.clone(),
+                new_login: user.login.clone(),
+                rank: new_rank,
+            });
+        }
+
+        if old_rank != new_rank {
+            movers.push(RankChange {
+                login: user.login.clone(),
+                old_rank,
+                new_rank,
+                change: old_rank as i64 - new_rank as i64,
+            });
+        }
+
+        if old_user.commits != user.commits {
+            commit_deltas.push(CommitChange {
+                login: user.login.clone(),
+                old_commits: old_user.commits,
+                new_commits: user.commits,
+                delta: user.commits - old_user.commits,
+            });
+        }
+    }
+
+    movers.sort_by_key(|mover| Reverse(mover.change.abs()));
+    movers.truncate(limit);
+    commit_deltas.sort_by_key(|delta| Reverse(delta.delta.abs()));
+    commit_deltas.truncate(limit);
+
+    let total_commits = |output: &Output| output.users.iter().map(|user| user.commits).sum();
+
+    SnapshotDiff {
+        generated_at: Change {
+            old: old.generated_at,
+            new: new.generated_at,
+        },
+        min_followers: Change {
+            old: old.min_followers,
+            new: new.min_followers,
+        },
+        users: Change {
+            old: old.users.len(),
+            new: new.users.len(),
+        },
+        total_commits: Change {
+            old: total_commits(old),
+            new: total_commits(new),
+        },
+        entered,
+        left: ranked(left),
+        blacklisted: ranked(blacklisted),
+        renamed,
+        movers,
+        commit_deltas,
+    }
+}
+
+impl Display for SnapshotDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Comparing {} to {}",
+            self.generated_at.old, self.generated_at.new
+        )?;
+        writeln!(f, "Users: {} -> {}", self.users.old, self.users.new)?;
+        writeln!(
+            f,
+            "Total commits: {} -> {} ({:+})",
+            self.total_commits.old,
+            self.total_commits.new,
+            self.total_commits.new - self.total_commits.old
+        )?;
+        if self.min_followers.is_changed() {
+            writeln!(
+                f,
+                "Min followers: {} -> {}",
+                self.min_followers.old, self.min_followers.new
+            )?;
+        }
+
+        let sections = [
+            ("Entered", &self.entered),
+            ("Left", &self.left),
+            ("Removed by blacklist", &self.blacklisted),
+        ];
+        for (title, users) in sections {
+            if users.is_empty() {
+                continue;
+            }
+            writeln!(f, "\n{} ({}):", title, users.len())?;
+            for user in users {
+                writeln!(
+                    f,
+                    "  #{} {} ({} commits)",
+                    user.rank, user.login, user.commits
+                )?;
+            }
+        }
+
+        if !self.renamed.is_empty() {
+            writeln!(f, "\nRenamed ({}):", self.renamed.len())?;
+            for rename in &self.renamed {
+                writeln!(
+                    f,
+                    "  #{} {} -> {}",
+                    rename.rank, rename.old_login, rename.new_login
+                )?;
+            }
+        }
+
+        if !self.movers.is_empty() {
+            writeln!(f, "\nBiggest rank changes:")?;
+            for mover in &self.movers {
+                writeln!(
+                    f,
+                    "  {} #{} -> #{} ({:+})",
+                    mover.login, mover.old_rank, mover.new_rank, mover.change
+                )?;
+            }
+        }
+
+        if !self.commit_deltas.is_empty() {
+            writeln!(f, "\nBiggest commit changes:")?;
+            for delta in &self.commit_deltas {
+                writeln!(
+                    f,
+                    "  {} {} -> {} ({:+})",
+                    delta.login, delta.old_commits, delta.new_commits, delta.delta
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
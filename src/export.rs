@@ -0,0 +1,160 @@
+use std::fmt::Write;
+
+use clap::ValueEnum;
+
+use crate::output::{Output, User};
+
+/// Formats that a leaderboard can be exported as
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ExportFormat {
+    Csv,
+    Markdown,
+    Html,
+}
+
+/// Names of the exported columns
+const HEADERS: &[&str] = &[
+    "Rank",
+    "Login",
+    "Name",
+    "Company",
+    "Commits",
+    "Pull Requests",
+    "Contributions",
+    "Public Contributions",
+    "Private Contributions",
+    "Followers",
+];
+
+/// Provides the exported column values for a user
+///
+/// # Arguments
+/// * rank - The rank of the user on the board
+/// * user - The user to get the values of
+fn columns(rank: usize, user: &User) -> [String; 10] {
+    [
+        rank.to_string(),
+        user.login.clone(),
+        user.name.clone().unwrap_or_default(),
+        user.company.clone().unwrap_or_default(),
+        user.commits.to_string(),
+        user.pull_requests.to_string(),
+        user.contribs
+            .map(|value| value.to_string())
+            .unwrap_or_default(),
+        user.pub_contribs.to_string(),
+        match (user.priv_contribs, user.priv_contribs_band) {
+            (Some(value), _) => value.to_string(),
+            (None, Some(band)) => band.to_string(),
+            (None, None) => String::new(),
+        },
+        user.followers.to_string(),
+    ]
+}
+
+/// Exports the leaderboard of the provided output in the provided format
+///
+/// # Arguments
+/// * output - The output to export
+/// * format - The format to export as
+/// * limit - Optional maximum number of users to include
+pub fn export(output: &Output, format: ExportFormat, limit: Option<usize>) -> String {
+    let users = output
+        .users
+        .iter()
+        .take(limit.unwrap_or(usize::MAX))
+        .enumerate()
+        .map(|(index, user)| columns(index + 1, user));
+
+    match format {
+        ExportFormat::Csv => {
+            let mut out = csv_row(HEADERS.iter().copied());
+            users.for_each(|row| out.push_str(&csv_row(row.iter().map(String::as_str))));
+            out
+        }
+        ExportFormat::Markdown => {
+            let mut out = format!("## {}\n\n", escape_markdown(&output.title));
+            out.push_str(&markdown_row(HEADERS.iter().copied()));
+            out.push_str(&markdown_row(HEADERS.iter().map(|_| "---")));
+            users.for_each(|row| out.push_str(&markdown_row(row.iter().map(String::as_str))));
+            out
+        }
+        ExportFormat::Html => {
+            let title = escape_html(&output.title);
+            let mut out = String::new();
+            let _ = writeln!(out, "<!DOCTYPE html>");
+            let _ = writeln!(out, "<html>");
+            let _ = writeln!(
+                out,
+                "<head><meta charset=\"utf-8\"><title>{}</title></head>",
+                title
+            );
+            let _ = writeln!(out, "<body>");
+            let _ = writeln!(out, "<table>");
+            let _ = writeln!(out, "<caption>{}</caption>", title);
+            out.push_str(&html_row("th", HEADERS.iter().copied()));
+            users.for_each(|row| out.push_str(&html_row("td", row.iter().map(String::as_str))));
+            let _ = writeln!(out, "</table>");
+            let _ = writeln!(out, "</body>");
+            let _ = writeln!(out, "</html>");
+            out
+        }
+    }
+}
+
+fn csv_row<'a>(values: impl Iterator<Item = &'a str>) -> String {
+    let mut row = values
+        .map(|value| {
+            if value.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push('\n');
+    row
+}
+
+fn markdown_row<'a>(values: impl Iterator<Item = &'a str>) -> String {
+    let mut row = String::from("|");
+    for value in values {
+        let _ = write!(row, " {} |", escape_markdown(value));
+    }
+    row.push('\n');
+    row
+}
+
+fn html_row<'a>(cell: &str, values: impl Iterator<Item = &'a str>) -> String {
+    let mut row = String::from("<tr>");
+    for value in values {
+        let _ = write!(row, "<{cell}>{}</{cell}>", escape_html(value));
+    }
+    row.push_str("</tr>\n");
+    row
+}
+
+/// Escapes characters that would break a markdown table cell
+fn escape_markdown(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace(['\r', '\n'], " ")
+}
+
+/// Escapes characters with special meaning in HTML
+pub fn escape_html(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
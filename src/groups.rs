@@ -0,0 +1,281 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::output::{Metric, User};
+
+/// Maximum number of groups included in each leaderboard
+const MAX_GROUPS: usize = 100;
+
+/// Number of top members listed for each group
+const TOP_MEMBERS: usize = 5;
+
+/// Minimum length of a mention's key for it to be merged into a company
+/// whose name extends it, shorter mentions are too ambiguous
+const MIN_MERGED_MENTION: usize = 4;
+
+/// Legal form words that are ignored when comparing company names
+const COMPANY_SUFFIXES: &[&str] = &[
+    "co",
+    "company",
+    "corp",
+    "corporation",
+    "gmbh",
+    "inc",
+    "incorporated",
+    "limited",
+    "llc",
+    "ltd",
+    "plc",
+    "pty",
+];
+
+/// Organization or company ranked by the combined commits of its
+/// members on the board
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct Group {
+    /// Name of the organization or company
+    pub name: String,
+    /// Number of members on the board
+    pub members: usize,
+    /// Combined commits of the members
+    pub commits: i64,
+    /// Combined contributions of the members, only the public contributions
+    /// are counted when any user on the board has redacted private
+    /// contributions
+    pub contribs: i64,
+    /// Logins of the members with the most commits
+    pub top_members: Vec<String>,
+}
+
+/// Ranks the organizations the users are members of
+///
+/// # Arguments
+/// * users - The users to rank the organizations of
+pub fn rank_organizations(users: &[User]) -> Vec<Group> {
+    let contribs = Metric::Contributions.ranked_by(users);
+    let mut groups: HashMap<&str, Vec<&User>> = HashMap::new();
+    for user in users {
+        for org in &user.orgs {
+            groups.entry(org.as_str()).or_default().push(user);
+        }
+    }
+
+    let groups = groups
+        .into_iter()
+        .map(|(name, members)| create_group(name.to_string(), members, contribs))
+        .collect();
+    rank_groups(groups)
+}
+
+/// Ranks the companies of the users, company names are normalized so that
+/// variants such as "@BeyondEssential" and "Beyond Essential Systems" are
+/// counted as the same company
+///
+/// # Arguments
+/// * users - The users to rank the companies of
+pub fn rank_companies(users: &[User]) -> Vec<Group> {
+    let contribs = Metric::Contributions.ranked_by(users);
+    // Members and the name variants used for each company
+    let mut groups: HashMap<String, (Vec<&User>, HashMap<&str, usize>)> = HashMap::new();
+
+    for user in users {
+        let Some(company) = &user.company else {
+            continue;
+        };
+
+        for name in split_companies(company) {
+            let key = normalize_company(name);
+            if key.is_empty() {
+                continue;
+            }
+
+            let (members, variants) = groups.entry(key).or_default();
+            if !members.iter().any(|member| member.login == user.login) {
+                members.push(user);
+            }
+            *variants.entry(name).or_default() += 1;
+        }
+    }
+
+    merge_mentions(&mut groups);
+
+    let groups = groups
+        .into_values()
+        .map(|(members, variants)| {
+            // Prefer the most common variant, then variants that aren't
+            // mentions, then the longest variant
+            let name = variants
+                .into_iter()
+                .max_by_key(|(name, count)| (*count, !name.starts_with('@'), name.len()))
+                .map(|(name, _)| name.trim_start_matches('@').to_string())
+                .unwrap_or_default();
+            create_group(name, members, contribs)
+        })
+        .collect();
+    rank_groups(groups)
+}
+
+/// Merges companies that are only mentioned by their GitHub handle into
+/// the one other company whose name extends the handle, such as
+/// "@BeyondEssential" into "Beyond Essential Systems". Mentions extended
+/// by several companies are ambiguous and kept on their own
+///
+/// # Arguments
+/// * groups - The members and name variants of each company by its key
+fn merge_mentions<'a>(groups: &mut HashMap<String, (Vec<&'a User>, HashMap<&'a str, usize>)>) {
+    let mut keys: Vec<String> = groups.keys().cloned().collect();
+    // Longer mentions are merged first so that merges don't depend on order
+    keys.sort_by_key(|key| (Reverse(key.len()), key.clone()));
+
+    for key in &keys {
+        let is_mention = groups
+            .get(key)
+            .is_some_and(|(_, variants)| variants.keys().any(|name| name.starts_with('@')));
+        if !is_mention || key.chars().count() < MIN_MERGED_MENTION {
+            continue;
+        }
+
+        let mut extended = keys
+            .iter()
+            .filter(|other| *other != key && other.starts_with(key.as_str()));
+        let (Some(target), None) = (extended.next(), extended.next()) else {
+            continue;
+        };
+        if !groups.contains_key(target) {
+            continue;
+        }
+
+        let (members, variants) = groups.remove(key).expect("Mention should be grouped");
+        let (target_members, target_variants) =
+            groups.get_mut(target).expect("Target should be grouped");
+        for member in members {
+            if !target_members
+                .iter()
+                .any(|existing| existing.login == member.login)
+            {
+                target_members.push(member);
+            }
+        }
+        for (name, count) in variants {
+            *target_variants.entry(name).or_default() += count;
+        }
+    }
+}
+
+/// Splits a company field mentioning several companies such
+/// as "@Magikcraft @Camunda" into each of the companies
+fn split_companies(company: &str) -> Vec<&str> {
+    let company = company.trim();
+    let mentions: Vec<&str> = company.split_whitespace().collect();
+    if mentions.len() > 1 && mentions.iter().all(|value| value.starts_with('@')) {
+        mentions
+    } else {
+        vec![company]
+    }
+}
+
+/// Normalizes a company name into a key used for comparing companies
+fn normalize_company(name: &str) -> String {
+    name.trim()
+        .trim_start_matches('@')
+        .split(|c: char| !c.is_alphanumeric())
+        .map(|word| word.to_lowercase())
+        .filter(|word| !word.is_empty() && !COMPANY_SUFFIXES.contains(&word.as_str()))
+        .collect()
+}
+
+fn create_group(name: String, mut members: Vec<&User>, contribs: Metric) -> Group {
+    members.sort_by_key(|user| Reverse(user.commits));
+    Group {
+        name,
+        members: members.len(),
+        commits: members.iter().map(|user| user.commits).sum(),
+        contribs: members.iter().map(|user| contribs.value(user)).sum(),
+        top_members: members
+            .iter()
+            .take(TOP_MEMBERS)
+            .map(|user| user.login.clone())
+            .collect(),
+    }
+}
+
+fn rank_groups(mut groups: Vec<Group>) -> Vec<Group> {
+    groups.sort_by(|a, b| {
+        b.commits
+            .cmp(&a.commits)
+            .then_with(|| b.members.cmp(&a.members))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    groups.truncate(MAX_GROUPS);
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn user(login: &str, company: &str, commits: i64) -> User {
+        serde_json::from_value(json!({
+            "login": login,
+            "previous_logins": [],
+            "avatar": "",
+            "company": company,
+            "social_accounts": [],
+            "orgs": [],
+            "orgs_total": 0,
+            "languages": [],
+            "followers": 0,
+            "pub_contribs": commits,
+            "commits": commits,
+            "pull_requests": 0
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn mentions_merge_with_the_company_they_shorten() {
+        let users = [
+            user("a", "@BeyondEssential", 10),
+            user("b", "Beyond Essential Systems", 5),
+            user("c", "Beyond Essential Systems", 2),
+            user("d", "Beyond Essential Systems Ltd", 1),
+        ];
+        let companies = rank_companies(&users);
+        assert_eq!(companies.len(), 1);
+        assert_eq!(companies[0].name, "Beyond Essential Systems");
+        assert_eq!(companies[0].members, 4);
+        assert_eq!(companies[0].commits, 18);
+    }
+
+    #[test]
+    fn ambiguous_mentions_are_kept_separate() {
+        let users = [
+            user("a", "@Google", 10),
+            user("b", "Google Cloud", 5),
+            user("c", "Google DeepMind", 1),
+        ];
+        assert_eq!(rank_companies(&users).len(), 3);
+    }
+
+    #[test]
+    fn legal_forms_are_ignored() {
+        let users = [user("a", "Acme Ltd", 1), user("b", "ACME, Inc.", 1)];
+        let companies = rank_companies(&users);
+        assert_eq!(companies.len(), 1);
+        assert_eq!(companies[0].members, 2);
+    }
+
+    #[test]
+    fn redacted_boards_sum_public_contributions() {
+        let mut users = [user("a", "Acme", 10), user("b", "Acme", 5)];
+        users[0].contribs = Some(100);
+        assert_eq!(rank_companies(&users)[0].contribs, 15);
+
+        users[1].contribs = Some(50);
+        assert_eq!(rank_companies(&users)[0].contribs, 150);
+    }
+}
@@ -0,0 +1,161 @@
+use std::{
+    collections::BTreeMap,
+    fs::read_to_string,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use thiserror::Error;
+
+use crate::output::write_atomic;
+
+/// Errors that could occur while reading or writing resolved identities
+#[derive(Debug, Error)]
+pub enum IdentityError {
+    #[error("Failed to read {path}: {err}")]
+    Read { path: PathBuf, err: std::io::Error },
+    #[error("Failed to parse {path}: {err}")]
+    Parse {
+        path: PathBuf,
+        err: serde_json::Error,
+    },
+    #[error("Failed to write {path}: {err}")]
+    Write { path: PathBuf, err: std::io::Error },
+}
+
+/// Node IDs of the users listed by login in the blacklist, opt-out and
+/// private lists. Logins are resolved once and stored so that entries
+/// keep matching their users after they rename
+pub struct Identities {
+    /// Path of the file the identities are stored in
+    path: PathBuf,
+    /// Node IDs by the login they were resolved from, logins that didn't
+    /// belong to a user are stored without one so they aren't looked up
+    /// again
+    ids: BTreeMap<String, Option<String>>,
+}
+
+impl Identities {
+    /// Reads the stored identities, a missing file has no identities
+    ///
+    /// # Arguments
+    /// * path - The path of the file the identities are stored in
+    pub fn read(path: &Path) -> Result<Self, IdentityError> {
+        let ids = match read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).map_err(|err| IdentityError::Parse {
+                path: path.to_path_buf(),
+                err,
+            })?,
+            Err(err) if err.kind() == ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => {
+                return Err(IdentityError::Read {
+                    path: path.to_path_buf(),
+                    err,
+                })
+            }
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            ids,
+        })
+    }
+
+    /// Provides the list entries that may be logins and haven't been
+    /// looked up yet. Entries with characters logins can't have, such as
+    /// node IDs and prefixed database IDs, are skipped
+    ///
+    /// # Arguments
+    /// * names - The list entries to check
+    pub fn unresolved<'a>(&self, names: impl IntoIterator<Item = &'a Box<str>>) -> Vec<String> {
+        let mut unresolved: Vec<String> = names
+            .into_iter()
+            .map(|name| name.as_ref())
+            .filter(|name| is_login(name))
+            .filter(|name| !self.ids.contains_key(*name))
+            .filter(|name| !self.ids.values().flatten().any(|id| id == name))
+            .map(|name| name.to_string())
+            .collect();
+        unresolved.sort();
+        unresolved.dedup();
+        unresolved
+    }
+
+    /// Stores the node ID a login resolved to
+    ///
+    /// # Arguments
+    /// * login - The login listed in a list file
+    /// * id - The node ID of the user with the login, if there is one
+    pub fn insert(&mut self, login: String, id: Option<String>) {
+        self.ids.insert(login, id);
+    }
+
+    /// Adds the resolved node IDs of the logins in the provided list
+    /// entries so that they match users that renamed since
+    ///
+    /// # Arguments
+    /// * names - The list entries to expand
+    pub fn expand(&self, mut names: Vec<Box<str>>) -> Vec<Box<str>> {
+        let ids: Vec<Box<str>> = names
+            .iter()
+            .filter_map(|name| self.ids.get(name.as_ref())?.as_ref())
+            .map(|id| Box::from(id.as_str()))
+            .collect();
+        names.extend(ids);
+        names
+    }
+
+    /// Writes the identities to the file they were read from
+    pub fn save(&self) -> Result<(), IdentityError> {
+        let json = serde_json::to_string_pretty(&self.ids).map_err(|err| IdentityError::Parse {
+            path: self.path.clone(),
+            err,
+        })?;
+        write_atomic(&self.path, json.as_bytes()).map_err(|err| IdentityError::Write {
+            path: self.path.clone(),
+            err,
+        })
+    }
+}
+
+/// Checks whether a list entry could be a login, logins only have
+/// letters, numbers and dashes
+///
+/// # Arguments
+/// * name - The list entry to check
+fn is_login(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_unknown_logins_are_resolved() {
+        let mut identities = Identities {
+            path: PathBuf::new(),
+            ids: BTreeMap::new(),
+        };
+        identities.insert("octocat".to_string(), Some("MDQ6VXNlcjE=".to_string()));
+        identities.insert("ghost-user".to_string(), None);
+
+        let names: Vec<Box<str>> = [
+            "octocat",
+            "ghost-user",
+            "new-user",
+            "MDQ6VXNlcjE=",
+            "U_kgDOBnVxqA",
+            "database_id:1",
+            "new-user",
+        ]
+        .into_iter()
+        .map(Box::from)
+        .collect();
+        assert_eq!(identities.unresolved(&names), ["new-user"]);
+
+        let expanded = identities.expand(names[..2].to_vec());
+        assert_eq!(expanded.len(), 3);
+        assert_eq!(expanded[2].as_ref(), "MDQ6VXNlcjE=");
+    }
+}
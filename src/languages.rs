@@ -0,0 +1,121 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::output::User;
+
+/// Maximum number of languages stored for each user
+const USER_LANGUAGES: usize = 5;
+
+/// Maximum number of languages included in the leaderboards
+const MAX_LANGUAGES: usize = 50;
+
+/// Number of top users listed for each language
+const TOP_USERS: usize = 10;
+
+/// Share of the commits of a user made in a language
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct LanguageShare {
+    /// Name of the language
+    pub name: String,
+    /// Share of the commits made in the language (0 to 1)
+    pub share: f64,
+}
+
+/// Leaderboard of the users working in a language
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct LanguageBoard {
+    /// Name of the language
+    pub name: String,
+    /// Number of users on the board working in the language
+    pub users: usize,
+    /// Estimated number of commits made in the language
+    pub commits: i64,
+    /// Users with the most estimated commits in the language
+    pub top_users: Vec<LanguageUser>,
+}
+
+/// User ranked within a language leaderboard
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct LanguageUser {
+    /// GitHub username
+    pub login: String,
+    /// Estimated number of commits made in the language
+    pub commits: i64,
+    /// Share of the commits of the user made in the language (0 to 1)
+    pub share: f64,
+}
+
+/// Creates the language shares of a user from the weight of
+/// each language, keeping only the heaviest languages
+///
+/// # Arguments
+/// * weights - The weight of each language
+pub fn language_shares(weights: HashMap<String, f64>) -> Vec<LanguageShare> {
+    let total: f64 = weights.values().sum();
+    if total <= 0.0 {
+        return Vec::new();
+    }
+
+    let mut shares: Vec<LanguageShare> = weights
+        .into_iter()
+        .map(|(name, weight)| LanguageShare {
+            name,
+            share: weight / total,
+        })
+        .collect();
+    shares.sort_by(|a, b| {
+        b.share
+            .total_cmp(&a.share)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    shares.truncate(USER_LANGUAGES);
+    shares
+}
+
+/// Ranks the languages the users work in, each user contributes
+/// their commits weighted by their share of the language
+///
+/// # Arguments
+/// * users - The users to rank the languages of
+pub fn rank_languages(users: &[User]) -> Vec<LanguageBoard> {
+    let mut languages: HashMap<&str, Vec<LanguageUser>> = HashMap::new();
+    for user in users {
+        for language in &user.languages {
+            languages
+                .entry(language.name.as_str())
+                .or_default()
+                .push(LanguageUser {
+                    login: user.login.clone(),
+                    commits: (user.commits as f64 * language.share).round() as i64,
+                    share: language.share,
+                });
+        }
+    }
+
+    let mut boards: Vec<LanguageBoard> = languages
+        .into_iter()
+        .map(|(name, mut users)| {
+            users.sort_by_key(|user| Reverse(user.commits));
+            let count = users.len();
+            let commits = users.iter().map(|user| user.commits).sum();
+            users.truncate(TOP_USERS);
+            LanguageBoard {
+                name: name.to_string(),
+                users: count,
+                commits,
+                top_users: users,
+            }
+        })
+        .collect();
+
+    boards.sort_by(|a, b| {
+        b.commits
+            .cmp(&a.commits)
+            .then_with(|| b.users.cmp(&a.users))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    boards.truncate(MAX_LANGUAGES);
+    boards
+}
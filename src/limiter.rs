@@ -0,0 +1,114 @@
+use std::{
+    sync::{Condvar, Mutex},
+    time::{Duration, Instant},
+};
+
+use log::debug;
+
+/// Number of GraphQL points GitHub allows to be spent per hour
+pub const POINTS_PER_HOUR: u32 = 5000;
+
+/// Maximum number of requests allowed to be in-flight at once
+pub const MAX_CONCURRENT: usize = 8;
+
+/// Shared token-bucket rate limiter used to keep concurrent preset
+/// crawls within the GitHub points-per-hour budget and the limit on
+/// concurrent requests
+pub struct RateLimiter {
+    /// Current state of the bucket
+    state: Mutex<LimiterState>,
+    /// Condition notified when a concurrent request slot is released
+    released: Condvar,
+    /// Maximum number of points the bucket can hold
+    capacity: f64,
+    /// Number of points refilled per second
+    refill_rate: f64,
+    /// Maximum number of requests allowed in-flight
+    max_concurrent: usize,
+}
+
+struct LimiterState {
+    /// Number of points currently available
+    points: f64,
+    /// The last time the bucket was refilled
+    last_refill: Instant,
+    /// Number of requests currently in-flight
+    in_flight: usize,
+}
+
+/// Guard for an acquired request slot, the slot is released
+/// when the permit is dropped
+pub struct Permit<'a> {
+    limiter: &'a RateLimiter,
+}
+
+impl RateLimiter {
+    /// Creates a new limiter with a full bucket
+    ///
+    /// # Arguments
+    /// * points_per_hour - The number of points refilled each hour
+    /// * max_concurrent - The maximum number of in-flight requests
+    pub fn new(points_per_hour: u32, max_concurrent: usize) -> Self {
+        let capacity = points_per_hour as f64;
+        Self {
+            state: Mutex::new(LimiterState {
+                points: capacity,
+                last_refill: Instant::now(),
+                in_flight: 0,
+            }),
+            released: Condvar::new(),
+            capacity,
+            refill_rate: capacity / 3600.0,
+            max_concurrent: max_concurrent.max(1),
+        }
+    }
+
+    /// Blocks until a request costing `cost` points is allowed to
+    /// be made, returning a permit that holds the concurrent slot
+    ///
+    /// # Arguments
+    /// * cost - The number of points the request will spend
+    pub fn acquire(&self, cost: u32) -> Permit<'_> {
+        let cost = (cost as f64).min(self.capacity);
+        let mut state = self.state.lock().expect("Rate limiter lock poisoned");
+
+        loop {
+            // Wait for a concurrent request slot
+            while state.in_flight >= self.max_concurrent {
+                state = self
+                    .released
+                    .wait(state)
+                    .expect("Rate limiter lock poisoned");
+            }
+
+            let now = Instant::now();
+            let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+            state.points = (state.points + elapsed * self.refill_rate).min(self.capacity);
+            state.last_refill = now;
+
+            if state.points >= cost {
+                state.points -= cost;
+                state.in_flight += 1;
+                return Permit { limiter: self };
+            }
+
+            // Wait for enough points to be refilled
+            let wait = Duration::from_secs_f64((cost - state.points) / self.refill_rate);
+            debug!("Rate limit budget exhausted, waiting {:?}", wait);
+            state = self
+                .released
+                .wait_timeout(state, wait)
+                .expect("Rate limiter lock poisoned")
+                .0;
+        }
+    }
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.limiter.state.lock() {
+            state.in_flight -= 1;
+        }
+        self.limiter.released.notify_all();
+    }
+}
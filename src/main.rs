@@ -1,389 +1,1284 @@
 use std::{
+    cmp::Reverse,
     collections::HashSet,
-    env::args,
-    fs::{create_dir_all, read_to_string, File},
-    io::Write,
-    path::Path,
-    thread::sleep,
-    time::Duration,
+    fs::{create_dir_all, read_dir, read_to_string, remove_file, write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    ptr,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread::scope,
 };
 
+use avatars::{remove_avatars, AvatarError, AvatarMirror};
+use badge::{render_badge, NOT_RANKED_COLOR, RANKED_COLOR};
+use batch::{BatchFetcher, Lookup};
+use changelog::render_changelog;
 use chrono::prelude::*;
-use graphql_client::{reqwest::post_graphql_blocking, GraphQLQuery};
-use log::{debug, error};
+use clap::Parser;
+use cli::{
+    BadgesArgs, BatchArgs, ChangelogArgs, Cli, Command, CrawlArgs, DiffArgs, EnrichArgs,
+    ExportArgs, PresetArgs, PresetsCommand, RefreshArgs, SchemaArgs,
+};
+use client::{load_tokens, ClientError, GitHubClient};
+use diff::{diff_outputs, DiffFormat};
+use export::export;
+use groups::{rank_companies, rank_organizations};
+use identities::{Identities, IdentityError};
+use languages::rank_languages;
+use limiter::{RateLimiter, MAX_CONCURRENT, POINTS_PER_HOUR};
+use log::{debug, error, info, warn};
+use output::{
+    output_schema, read_output, validate_schema, write_output, Output, OutputResult, User,
+    SCHEMA_VERSION,
+};
 use preset::LocationPreset;
-use reqwest::{
-    blocking::Client,
-    header::{HeaderMap, HeaderValue, InvalidHeaderValue, AUTHORIZATION},
+use privacy::Privacy;
+use search::{
+    build_query, count_users, fetch_languages, fetch_users, query_problems, search_users,
+    split_preset, SearchError, MAX_QUERY_LENGTH, PER_PAGE, REQUEST_COST, USERS, USER_FIELDS,
+    USER_LANGUAGES,
 };
-use serde::{Deserialize, Serialize};
+use stats::Stats;
 use thiserror::Error;
 
-use crate::preset::PRESET;
+use crate::preset::{find_presets, PresetError, PRESET, PRESETS};
 
+mod avatars;
+mod badge;
+mod batch;
+mod changelog;
+mod cli;
+mod client;
+mod diff;
+mod export;
+mod groups;
+mod identities;
+mod languages;
+mod limiter;
+mod output;
 mod preset;
+mod privacy;
+mod search;
+mod stats;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
-fn main() {
+/// Errors that cause the loader to exit unsuccessfully
+#[derive(Debug, Error)]
+pub enum LoaderError {
+    #[error(transparent)]
+    Client(#[from] ClientError),
+    #[error(transparent)]
+    Search(#[from] SearchError),
+    #[error("Failed to mirror avatars: {0}")]
+    Avatars(#[from] AvatarError),
+    #[error("Failed to read {path}: {err}")]
+    ReadFile { path: PathBuf, err: std::io::Error },
+    #[error(transparent)]
+    Identities(#[from] IdentityError),
+    #[error(transparent)]
+    Snapshot(#[from] OutputResult),
+    #[error("Failed to write {path}: {err}")]
+    Output { path: PathBuf, err: OutputResult },
+    #[error(transparent)]
+    Preset(#[from] PresetError),
+    #[error("{path} has {problems} problem(s)")]
+    InvalidOutput { path: PathBuf, problems: usize },
+    #[error("{0} preset(s) have problems")]
+    InvalidPresets(usize),
+    #[error("{failed} of {total} preset(s) failed to complete")]
+    PresetsFailed { failed: usize, total: usize },
+}
+
+fn main() -> ExitCode {
     let _ = dotenvy::dotenv();
     env_logger::init();
 
-    // Remove blacklisted users
-    let blacklist = read_blacklist();
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            error!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
 
-    let token = std::env::var("GITHUB_TOKEN").unwrap();
+fn run() -> Result<(), LoaderError> {
+    let cli = Cli::parse();
 
-    // Handle blacklist purging only
-    if let Some(arg) = args().nth(1) {
-        // Remove items from the current list that are present in the blacklist
-        if arg == "blacklist" {
-            let data = Path::new("data");
-            if !data.exists() {
-                return;
-            }
+    match &cli.command {
+        Command::Crawl(args) => crawl(&cli, args),
+        Command::Refresh(args) => refresh(&cli, args),
+        Command::Blacklist => purge_blacklist(&cli),
+        Command::Optout => purge_optout(&cli),
+        Command::Presets(PresetsCommand::List) => {
+            list_presets();
+            Ok(())
+        }
+        Command::Presets(PresetsCommand::Validate) => validate_presets(),
+        Command::Validate => validate_output(&cli),
+        Command::Schema(args) => print_schema(&cli, args),
+        Command::Export(args) => export_snapshot(&cli, args),
+        Command::Badges(args) => generate_badges(&cli, args),
+        Command::Diff(args) => diff_snapshots(&cli, args),
+        Command::Changelog(args) => print_changelog(&cli, args),
+    }
+}
 
-            let out = data.join("output.json");
-            let json = read_to_string(&out).expect("Failed to read output.json");
-            let mut output: Output =
-                serde_json::from_str(&json).expect("Failed to parse output.json");
-            // Remove all users that are contained in the blacklist
-            output.users.retain(|user| {
-                !blacklist
-                    .iter()
-                    .any(|blacklist| user.login.eq(blacklist.as_ref()))
-            });
+/// Prints the changelog between a previous snapshot and the current
+/// output, or another snapshot
+///
+/// # Arguments
+/// * cli - The command line options
+/// * args - The changelog arguments
+fn print_changelog(cli: &Cli, args: &ChangelogArgs) -> Result<(), LoaderError> {
+    let optout = read_list(cli, &cli.optout)?;
+    let privacy = read_privacy(cli)?;
+    let previous = read_snapshot(&args.previous, &optout, &privacy)?;
+    let current = read_snapshot(
+        &args.current.clone().unwrap_or_else(|| cli.output_path()),
+        &optout,
+        &privacy,
+    )?;
+    print!("{}", render_changelog(&previous, &current));
+    Ok(())
+}
 
-            // Sort the users again to be safe
-            output.users.sort_by(|a, b| b.commits.cmp(&a.commits));
+/// Prints the differences between two stored snapshots
+///
+/// # Arguments
+/// * cli - The command line options
+/// * args - The diff arguments
+fn diff_snapshots(cli: &Cli, args: &DiffArgs) -> Result<(), LoaderError> {
+    let blacklist = read_list(cli, &cli.blacklist)?;
+    let optout = read_list(cli, &cli.optout)?;
+    let privacy = read_privacy(cli)?;
+    let old = read_snapshot(&args.old, &optout, &privacy)?;
+    let new = read_snapshot(&args.new, &optout, &privacy)?;
+    let diff = diff_outputs(&old, &new, &blacklist, args.limit);
 
-            let json: String = serde_json::to_string(&output).expect("Failed to serialize output");
-            let mut file = File::create(out).expect("Failed to create output file");
-            file.write_all(json.as_bytes())
-                .expect("Failed to write output file");
-            return;
-        }
+    match args.format {
+        DiffFormat::Text => print!("{}", diff),
+        DiffFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&diff).expect("Diff should be serializable")
+        ),
     }
 
-    let mut headers = HeaderMap::with_capacity(1);
-    headers.insert(
-        AUTHORIZATION,
-        HeaderValue::from_str(&format!("Bearer {}", token)).expect("Failed to create token header"),
-    );
+    Ok(())
+}
 
-    let client = match Client::builder()
-        .user_agent(format!("Committer/{}", VERSION))
-        .default_headers(headers)
-        .build()
-    {
-        Ok(value) => value,
-        Err(error) => {
-            error!("Failed to create request client: {}", error);
-            return;
-        }
+/// Renders rank badges for each user ranked in the stored snapshots
+/// of the selected presets. Badges are written to
+/// {out}/{preset}/{metric}/{login}.svg along with a not-ranked.svg
+/// badge for users that aren't on the board
+///
+/// # Arguments
+/// * cli - The command line options
+/// * args - The badges arguments
+fn generate_badges(cli: &Cli, args: &BadgesArgs) -> Result<(), LoaderError> {
+    let optout = read_list(cli, &cli.optout)?;
+    let privacy = read_privacy(cli)?;
+    let presets = if args.preset.is_empty() {
+        PRESETS.iter().collect()
+    } else {
+        find_presets(&args.preset)?
     };
+    let out = args
+        .out
+        .clone()
+        .unwrap_or_else(|| cli.data_dir.join("badges"));
 
-    let preset = &PRESET;
+    for preset in presets {
+        let path = snapshot_path(cli, preset);
+        if !path.exists() {
+            debug!("Skipping badges for {}, no snapshot stored", preset.title);
+            continue;
+        }
 
-    debug!("Starting preset: {}", preset.title);
-    let (users, min_followers) = match search_users(&client, &blacklist, preset) {
-        Ok(value) => value,
-        Err(err) => {
-            error!("Failed to complete preset {}: {}", preset.title, err);
-            return;
+        let output = read_snapshot(&path, &optout, &privacy)?;
+
+        for metric in &args.metric {
+            let dir = out.join(preset.slug()).join(metric.slug());
+            let label = format!(
+                "{} {} Rank",
+                preset.title,
+                metric.ranked_by(&output.users).label()
+            );
+
+            let mut badges: Vec<(String, String)> = metric
+                .rank(&output.users)
+                .into_iter()
+                .enumerate()
+                .map(|(index, user)| {
+                    let badge = render_badge(&label, &format!("#{}", index + 1), RANKED_COLOR);
+                    (format!("{}.svg", user.login), badge)
+                })
+                .collect();
+            badges.push((
+                "not-ranked.svg".to_string(),
+                render_badge(&label, "Not ranked", NOT_RANKED_COLOR),
+            ));
+
+            info!(
+                "Rendering {} badges for {} to {}",
+                badges.len(),
+                preset.title,
+                dir.display()
+            );
+            if cli.dry_run {
+                continue;
+            }
+
+            for (name, badge) in badges {
+                write_file(&dir.join(name), badge)?;
+            }
+
+            // Remove badges left over from before users opted out
+            for login in &optout {
+                let _ = remove_file(dir.join(format!("{}.svg", login)));
+            }
         }
-    };
-    if let Err(err) = produce_output(users, preset.title, min_followers) {
-        error!(
-            "Failed to produce preset output for {}: {}",
-            preset.title, err
-        );
-        return;
     }
-    debug!("Finished preset: {}", preset.title);
-}
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct Output {
-    title: String,
-    min_followers: i64,
-    generated_at: DateTime<Utc>,
-    users: Vec<User>,
+    Ok(())
 }
 
-#[derive(Debug, Error)]
-pub enum OutputResult {
-    #[error("Error while serializing results: {0}")]
-    Serialize(#[from] serde_json::Error),
-    #[error("Failed to create/write output file: {0}")]
-    WriteFile(#[from] std::io::Error),
+/// Writes the provided contents to a file, creating any
+/// missing parent directories
+///
+/// # Arguments
+/// * path - The path of the file to write
+/// * contents - The contents to write
+fn write_file(path: &Path, contents: impl AsRef<[u8]>) -> Result<(), LoaderError> {
+    let result = match path.parent() {
+        Some(parent) => create_dir_all(parent),
+        None => Ok(()),
+    }
+    .and_then(|_| write(path, contents));
+
+    result.map_err(|err| LoaderError::Output {
+        path: path.to_path_buf(),
+        err: err.into(),
+    })
 }
 
-/// Writes the output file for the provided users, title
-/// and min followers
+/// Exports the leaderboard of a stored snapshot
 ///
 /// # Arguments
-/// * users - The collection of users
-/// * title - The name of the preset file
-/// * min_followers - The min follower count
-fn produce_output(
-    mut users: Vec<User>,
-    title: &str,
-    min_followers: i64,
-) -> Result<(), OutputResult> {
-    let data = Path::new("data");
-    if !data.exists() {
-        create_dir_all(data).expect("Failed to create data directory");
+/// * cli - The command line options
+/// * args - The export arguments
+fn export_snapshot(cli: &Cli, args: &ExportArgs) -> Result<(), LoaderError> {
+    let input = args.input.clone().unwrap_or_else(|| cli.output_path());
+    let optout = read_list(cli, &cli.optout)?;
+    let output = read_snapshot(&input, &optout, &read_privacy(cli)?)?;
+    let exported = export(&output, args.format, args.limit);
+
+    let Some(path) = &args.out else {
+        print!("{}", exported);
+        return Ok(());
+    };
+
+    if cli.dry_run {
+        info!("Dry run, skipping write of {}", path.display());
+        return Ok(());
     }
 
-    // Sort the results by number of commits
-    users.sort_by(|a, b| b.commits.cmp(&a.commits));
+    write_file(path, exported)
+}
 
-    let out = data.join("output.json");
+/// Prints or writes the JSON Schema for the output format
+///
+/// # Arguments
+/// * cli - The command line options
+/// * args - The schema arguments
+fn print_schema(cli: &Cli, args: &SchemaArgs) -> Result<(), LoaderError> {
+    let schema = serde_json::to_string_pretty(&output_schema())
+        .expect("Output schema should be serializable");
 
-    let output = Output {
-        title: title.to_string(),
-        min_followers,
-        generated_at: Utc::now(),
-        users,
+    let Some(path) = &args.out else {
+        println!("{}", schema);
+        return Ok(());
     };
 
-    let json: String = serde_json::to_string(&output)?;
-    let mut file = File::create(out)?;
-    file.write_all(json.as_bytes())?;
-    Ok(())
+    if cli.dry_run {
+        info!("Dry run, skipping write of {}", path.display());
+        return Ok(());
+    }
+
+    write_file(path, schema)
 }
 
-/// Reads the collection of blacklisted named from the
-/// blacklist.txt file
-fn read_blacklist() -> Vec<Box<str>> {
-    let path = Path::new("blacklist.txt");
-    if !path.exists() {
-        return Vec::with_capacity(0);
+/// Crawls the presets selected by the provided arguments
+///
+/// # Arguments
+/// * cli - The command line options
+/// * args - The crawl arguments
+fn crawl(cli: &Cli, args: &CrawlArgs) -> Result<(), LoaderError> {
+    let presets = select_presets(&args.presets)?;
+    let crawler = Crawler::new(cli, &args.batch, &args.enrich)?;
+
+    if cli.dry_run {
+        return plan_presets(&crawler, &presets, args);
     }
 
-    let file = read_to_string(path).expect("Failed to read blacklist file");
-    file.lines()
-        .map(|line| line.trim())
-        .filter(|line| !line.is_empty() && !line.starts_with('#'))
-        .map(Box::from)
-        .collect()
+    let failed = crawler.run(&presets, args.workers, |preset| {
+        Ok(search_users(
+            &crawler.client,
+            &crawler.limiter,
+            &crawler.excluded,
+            preset,
+        )?)
+    });
+    crawler.finish(failed, presets.len())
 }
 
-#[allow(clippy::upper_case_acronyms)]
-type URI = String;
+/// Refreshes the users in the stored snapshots of the presets selected by
+/// the provided arguments, fetching the users in batches rather than
+/// searching for them. Users that were blacklisted, opted out or no longer
+/// exist are removed but no new users are found
+///
+/// # Arguments
+/// * cli - The command line options
+/// * args - The refresh arguments
+fn refresh(cli: &Cli, args: &RefreshArgs) -> Result<(), LoaderError> {
+    let presets: Vec<&LocationPreset> = select_presets(&args.presets)?
+        .into_iter()
+        .filter(|preset| {
+            let stored = snapshot_path(cli, preset).exists();
+            if !stored {
+                info!("Skipping {}, no snapshot stored", preset.title);
+            }
+            stored
+        })
+        .collect();
 
-#[derive(GraphQLQuery)]
-#[graphql(
-    schema_path = "src/schema.graphql",
-    query_path = "src/users.graphql",
-    response_derives = "Debug"
-)]
-struct UsersQuery;
+    let crawler = Crawler::new(cli, &args.batch, &args.enrich)?;
 
-/// Errors that could occur while searching for users
-#[derive(Debug, Error)]
-enum SearchError {
-    #[error("Invalid token header: {0}")]
-    InvalidTokenHeader(#[from] InvalidHeaderValue),
-    #[error("Ran out of attempts and failed request")]
-    FailedRequest(#[from] reqwest::Error),
-    #[error("Request encountered errors")]
-    RequestErrors,
-    #[error("Request missing data")]
-    MissingData,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-struct User {
-    login: String,
-    avatar: String,
-    name: Option<String>,
-    company: Option<String>,
-    orgs: Vec<String>,
-    followers: i64,
-    contribs: i64,
-    pub_contribs: i64,
-    priv_contribs: i64,
-    commits: i64,
-    pull_requests: i64,
-}
-
-/// Searches for and collects users from GitHub
+    if cli.dry_run {
+        for preset in presets {
+            let previous = read_snapshot(
+                &snapshot_path(cli, preset),
+                &crawler.excluded,
+                &crawler.privacy,
+            )?;
+            let (requests, points) = crawler.batcher.estimate(&USER_FIELDS, previous.users.len());
+            println!(
+                "{}: {} users in {} requests, {} points",
+                preset.title,
+                previous.users.len(),
+                requests,
+                points
+            );
+        }
+        return Ok(());
+    }
+
+    let failed = crawler.run(&presets, args.workers, |preset| {
+        let previous = read_snapshot(
+            &snapshot_path(cli, preset),
+            &crawler.excluded,
+            &crawler.privacy,
+        )?;
+
+        // Users are looked up by their node ID so that renamed users are
+        // still found, older snapshots without IDs fall back to the login
+        let (ids, logins): (Vec<&User>, Vec<&User>) =
+            previous.users.iter().partition(|user| user.id.is_some());
+        let ids: Vec<String> = ids.into_iter().filter_map(|user| user.id.clone()).collect();
+        let logins: Vec<String> = logins.into_iter().map(|user| user.login.clone()).collect();
+
+        let mut fetched = fetch_users(
+            &crawler.client,
+            &crawler.limiter,
+            &crawler.batcher,
+            Lookup::NodeId,
+            &ids,
+        );
+        let by_login = fetch_users(
+            &crawler.client,
+            &crawler.limiter,
+            &crawler.batcher,
+            Lookup::Login,
+            &logins,
+        );
+        fetched.users.extend(by_login.users);
+        fetched.failed.extend(by_login.failed);
+
+        // Users that couldn't be fetched keep their previous details rather
+        // than dropping off the board, users that no longer exist are removed
+        let mut users = Vec::with_capacity(previous.users.len());
+        for previous_user in previous.users {
+            let key = previous_user.id.as_ref().unwrap_or(&previous_user.login);
+            match fetched.users.remove(key) {
+                // Users may have renamed to a login that is excluded
+                Some(user) if user.is_listed(&crawler.excluded) => {}
+                Some(mut user) => {
+                    // Keep the previous languages when they aren't fetched again
+                    if !args.enrich.languages {
+                        user.languages = previous_user.languages;
+                    }
+                    users.push(user);
+                }
+                None if fetched.failed.contains(key) => {
+                    warn!("Keeping previous details of {}", previous_user.login);
+                    users.push(previous_user);
+                }
+                None => {}
+            }
+        }
+
+        Ok((users, previous.min_followers))
+    });
+    crawler.finish(failed, presets.len())
+}
+
+/// Provides the presets selected by the provided arguments,
+/// the default preset is used when none are selected
 ///
 /// # Arguments
-/// * client - The client to make the graphql requests
-/// * blacklist - List of blacklisted names
-/// * location - The location data for the request
-fn search_users(
-    client: &Client,
-    blacklist: &[Box<str>],
-    location: &LocationPreset,
-) -> Result<(Vec<User>, i64), SearchError> {
-    /// GitHub API URL for GraphQL
-    const GRAPHQL_URL: &str = "https://api.github.com/graphql";
+/// * args - The preset selection arguments
+fn select_presets(args: &PresetArgs) -> Result<Vec<&'static LocationPreset>, LoaderError> {
+    if args.all {
+        Ok(PRESETS.iter().collect())
+    } else if args.preset.is_empty() {
+        Ok(vec![PRESET])
+    } else {
+        Ok(find_presets(&args.preset)?)
+    }
+}
 
-    /// The number of users to collect (Considered amount)
-    const USERS: usize = 1000;
+/// State shared between the workers collecting the users of presets
+struct Crawler<'a> {
+    /// The command line options
+    cli: &'a Cli,
+    /// The client to make the graphql requests
+    client: GitHubClient,
+    /// The shared rate limiter
+    limiter: RateLimiter,
+    /// List of blacklisted and opted out names
+    excluded: Vec<Box<str>>,
+    /// The fetcher for batched requests of users
+    batcher: BatchFetcher,
+    /// The extra details to collect for each user
+    enrich: &'a EnrichArgs,
+    /// The mirror to store avatars in when mirroring
+    avatars: Option<AvatarMirror>,
+    /// The settings for redacting private contributions
+    privacy: Privacy,
+}
 
-    const PER_PAGE: usize = 5;
-    const MAX_PER_QUERY: usize = 1000;
+impl<'a> Crawler<'a> {
+    /// Creates the client, rate limiter and settings for collecting users
+    ///
+    /// # Arguments
+    /// * cli - The command line options
+    /// * batch - The options for batched requests
+    /// * enrich - The extra details to collect for each user
+    fn new(cli: &'a Cli, batch: &BatchArgs, enrich: &'a EnrichArgs) -> Result<Self, LoaderError> {
+        let client = GitHubClient::new(load_tokens()?)?;
+        info!("Using {} GitHub token(s)", client.token_count());
 
-    /// Maximum number of times a request can retry before failing
-    const MAX_ATTEMPTS: usize = 10;
+        // Each token has its own points budget
+        let points_per_hour = POINTS_PER_HOUR * client.token_count() as u32;
+        let limiter = RateLimiter::new(points_per_hour, MAX_CONCURRENT);
+        let batcher = BatchFetcher::new(batch.batch_size, batch.batch_cost);
 
-    let mut known_logins = HashSet::new();
+        resolve_identities(cli, &client, &limiter, &batcher)?;
+
+        // Remove blacklisted and opted out users
+        let mut excluded = read_list(cli, &cli.blacklist)?;
+        excluded.extend(read_list(cli, &cli.optout)?);
+
+        let avatars = if enrich.mirror_avatars {
+            Some(AvatarMirror::new(
+                cli.avatar_dir(),
+                enrich.avatar_prefix.clone(),
+            )?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            cli,
+            client,
+            limiter,
+            excluded,
+            batcher,
+            enrich,
+            avatars,
+            privacy: read_privacy(cli)?,
+        })
+    }
+
+    /// Collects the users of the provided presets concurrently and writes
+    /// their outputs, sharing the request client and rate limit budget
+    /// between each of the workers. Returns the number of presets that failed
+    ///
+    /// # Arguments
+    /// * presets - The presets to collect
+    /// * workers - The number of presets to collect at the same time
+    /// * collect - Function collecting the users and min followers of a preset
+    fn run<F>(&self, presets: &[&LocationPreset], workers: usize, collect: F) -> usize
+    where
+        F: Fn(&LocationPreset) -> Result<(Vec<User>, i64), LoaderError> + Sync,
+    {
+        let next = AtomicUsize::new(0);
+        let failed = AtomicUsize::new(0);
+        let total = presets.len();
+
+        scope(|scope| {
+            for _ in 0..workers.clamp(1, total.max(1)) {
+                scope.spawn(|| loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(preset) = presets.get(index) else {
+                        break;
+                    };
+
+                    info!(
+                        "Starting preset: {} ({}/{})",
+                        preset.title,
+                        index + 1,
+                        total
+                    );
+
+                    let result = collect(preset).and_then(|(users, min_followers)| {
+                        self.write(preset, users, min_followers)
+                    });
+                    if let Err(err) = result {
+                        error!("Failed to complete preset {}: {}", preset.title, err);
+                        failed.fetch_add(1, Ordering::Relaxed);
+                        continue;
+                    }
+
+                    info!(
+                        "Finished preset: {} ({}/{})",
+                        preset.title,
+                        index + 1,
+                        total
+                    );
+                });
+            }
+        });
+
+        failed.into_inner()
+    }
 
-    let mut users: Vec<User> = Vec::new();
-    let mut last_cursor: Option<String> = None;
+    /// Collects the extra details of the users and writes the
+    /// output and changelog for a preset
+    ///
+    /// # Arguments
+    /// * preset - The preset the users were collected for
+    /// * users - The collected users
+    /// * min_followers - The min follower count
+    fn write(
+        &self,
+        preset: &LocationPreset,
+        mut users: Vec<User>,
+        min_followers: i64,
+    ) -> Result<(), LoaderError> {
+        if self.enrich.languages {
+            fetch_languages(&self.client, &self.limiter, &self.batcher, &mut users);
+        }
+
+        if let Some(avatars) = &self.avatars {
+            avatars.mirror(&mut users);
+        }
 
-    let mut attempts = 0;
+        self.privacy.apply(&mut users);
 
-    let mut min_followers = -1;
+        let mut output = create_output(users, preset, min_followers);
 
-    'outer: while users.len() < USERS {
-        let mut query = String::new();
+        // Read the previous snapshot before it's replaced
+        let previous = read_previous(self.cli, preset, &self.excluded, &self.privacy);
 
-        for location in location.include {
-            query.push_str(" location:");
-            query.push_str(location);
+        if let Some(previous) = &previous {
+            for (old_login, new_login) in output.track_renames(previous) {
+                info!("{}: {} renamed to {}", preset.title, old_login, new_login);
+            }
         }
 
-        for location in location.exclude {
-            query.push_str(" -location:");
-            query.push_str(location);
+        write_outputs(&output_paths(self.cli, preset), &output)?;
+
+        if let Some(previous) = previous {
+            write_changelog(self.cli, preset, &previous, &output)?;
         }
+        Ok(())
+    }
 
-        if min_followers >= 0 {
-            query.push_str(" followers:<");
-            query.push_str(&min_followers.to_string());
+    /// Saves the state of the mirrored avatars and fails when any
+    /// of the presets failed
+    ///
+    /// # Arguments
+    /// * failed - The number of presets that failed
+    /// * total - The total number of presets
+    fn finish(&self, failed: usize, total: usize) -> Result<(), LoaderError> {
+        if let Some(avatars) = &self.avatars {
+            avatars.save()?;
         }
 
-        query.push_str(" sort:followers-desc");
+        if failed > 0 {
+            return Err(LoaderError::PresetsFailed { failed, total });
+        }
 
-        for _ in 1..(MAX_PER_QUERY / PER_PAGE) {
-            let variables = users_query::Variables {
-                query: query.clone(),
-                first: PER_PAGE as i64,
-                after: last_cursor.take(),
-            };
+        Ok(())
+    }
+}
+
+/// Prints the search queries of the provided presets along with an estimate
+/// of the requests a crawl would make, using a single request per preset to
+/// count the matching users rather than collecting them
+///
+/// # Arguments
+/// * crawler - The crawler the presets would be collected with
+/// * presets - The presets to plan
+/// * args - The crawl options
+fn plan_presets(
+    crawler: &Crawler,
+    presets: &[&LocationPreset],
+    args: &CrawlArgs,
+) -> Result<(), LoaderError> {
+    let mut total_requests = 0;
+    let mut total_points = 0;
+    let mut over_limits = 0;
+
+    for preset in presets {
+        println!("{} ({})", preset.title, preset.slug());
+
+        let mut preset_users = 0;
+        let mut pages = 0;
+
+        for query in split_preset(preset) {
+            let problems = query_problems(&query);
+            let query = build_query(&query, None);
+
+            println!("  Query: {}", query);
+            println!("    Length: {}/{}", query.chars().count(), MAX_QUERY_LENGTH);
+            for problem in &problems {
+                println!("    {}", problem);
+            }
+            if !problems.is_empty() {
+                over_limits += 1;
+            }
 
-            let res = match post_graphql_blocking::<UsersQuery, _>(client, GRAPHQL_URL, variables) {
+            let user_count = match count_users(&crawler.client, &crawler.limiter, &query) {
                 Ok(value) => value,
                 Err(err) => {
-                    attempts += 1;
-                    if attempts < MAX_ATTEMPTS {
-                        error!("Failed request (retry in 10s): {}", err);
-
-                        // Sleep for 10 seconds before trying again
-                        sleep(Duration::from_secs(10));
-                        continue;
-                    } else {
-                        return Err(SearchError::FailedRequest(err));
-                    }
+                    println!("    Failed to count users: {}", err);
+                    continue;
                 }
             };
 
-            if let Some(errors) = res.errors {
-                attempts += 1;
-                if attempts < MAX_ATTEMPTS {
-                    error!("Request errored (retry in 10s): {:?}", errors);
+            let users = (user_count.max(0) as usize).min(USERS);
+            pages += users.div_ceil(PER_PAGE);
+            preset_users += users;
+            println!("    Matching users: {}", user_count);
+        }
 
-                    // Sleep for 10 seconds before trying again
-                    sleep(Duration::from_secs(10));
-                    continue;
-                } else {
-                    return Err(SearchError::RequestErrors);
+        // Languages are fetched in batches of the kept users
+        let users = preset_users.min(USERS);
+        let (mut requests, mut points) = (pages, pages as u32 * REQUEST_COST);
+        if args.enrich.languages {
+            let (language_requests, language_points) =
+                crawler.batcher.estimate(&USER_LANGUAGES, users);
+            requests += language_requests;
+            points += language_points;
+        }
+        total_requests += requests;
+        total_points += points;
+
+        println!(
+            "  Estimate: {} users, {} pages, {} requests, {} points",
+            users, pages, requests, points
+        );
+    }
+
+    let client = &crawler.client;
+    let points_per_hour = POINTS_PER_HOUR * client.token_count() as u32;
+    println!(
+        "\nTotal: {} requests, {} points (~{:.1} hours with {} token(s))",
+        total_requests,
+        total_points,
+        total_points as f64 / points_per_hour as f64,
+        client.token_count()
+    );
+
+    if over_limits > 0 {
+        println!(
+            "{} queries are beyond the GitHub search limits",
+            over_limits
+        );
+    }
+
+    Ok(())
+}
+
+/// Removes blacklisted users from every stored snapshot and redacts
+/// private contributions following the privacy settings
+///
+/// # Arguments
+/// * cli - The command line options
+fn purge_blacklist(cli: &Cli) -> Result<(), LoaderError> {
+    let blacklist = read_list(cli, &cli.blacklist)?;
+    let privacy = read_privacy(cli)?;
+
+    for paths in stored_snapshots(cli)? {
+        let Some(path) = paths.first().filter(|path| path.exists()) else {
+            continue;
+        };
+
+        let mut output = read_output(path)?;
+
+        // Remove all users that are contained in the blacklist
+        output.users.retain(|user| {
+            let blacklisted = user.is_listed(&blacklist);
+            if blacklisted {
+                info!(
+                    "Removing blacklisted user: {} ({})",
+                    user.login,
+                    user.id.as_deref().unwrap_or("no ID")
+                );
+            }
+            !blacklisted
+        });
+
+        privacy.apply(&mut output.users);
+
+        // Sort the users again to be safe
+        output.users.sort_by_key(|user| Reverse(user.commits));
+        output.update_aggregates();
+
+        if cli.dry_run {
+            info!("Dry run, skipping write of {:?}", paths);
+            continue;
+        }
+
+        write_outputs(&paths, &output)?;
+    }
+
+    Ok(())
+}
+
+/// Provides the paths of every stored snapshot, each snapshot is listed
+/// with all of the paths it is written to
+///
+/// # Arguments
+/// * cli - The command line options
+fn stored_snapshots(cli: &Cli) -> Result<Vec<Vec<PathBuf>>, LoaderError> {
+    // The default preset is also written to each of the output paths
+    let mut snapshots: Vec<Vec<PathBuf>> = vec![cli.output_paths()];
+    snapshots.extend(
+        list_files(&cli.data_dir.join("presets"))?
+            .into_iter()
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .map(|path| vec![path]),
+    );
+    Ok(snapshots)
+}
+
+/// Removes opted out users from every stored snapshot along with their
+/// changelog entries, badges and mirrored avatars
+///
+/// # Arguments
+/// * cli - The command line options
+fn purge_optout(cli: &Cli) -> Result<(), LoaderError> {
+    let optout = read_list(cli, &cli.optout)?;
+    if optout.is_empty() {
+        return Ok(());
+    }
+
+    let snapshots = stored_snapshots(cli)?;
+
+    // Opted out users may be listed by ID or an old login, the logins they
+    // were stored under are collected to find their other files
+    let mut logins: Vec<Box<str>> = optout.clone();
+    let mut ids: Vec<Box<str>> = Vec::new();
+
+    for paths in snapshots {
+        let Some(path) = paths.first().filter(|path| path.exists()) else {
+            continue;
+        };
+
+        let mut output = read_output(path)?;
+        let removed = output.remove_users(&optout);
+        if removed.is_empty() {
+            continue;
+        }
+
+        let mut removed_logins = Vec::with_capacity(removed.len());
+        for user in removed {
+            if let Some(id) = user.id {
+                ids.push(id.into());
+            }
+            for login in user.previous_logins.into_iter().chain([user.login]) {
+                if !logins.iter().any(|known| known.as_ref() == login) {
+                    logins.push(login.as_str().into());
                 }
+                removed_logins.push(login);
             }
+        }
+
+        info!(
+            "Removing opted out user(s) {} from {}",
+            removed_logins.join(", "),
+            path.display()
+        );
+        if !cli.dry_run {
+            write_outputs(&paths, &output)?;
+        }
+    }
 
-            let data = match res.data {
-                Some(value) => value,
-                None => {
-                    attempts += 1;
-                    if attempts < MAX_ATTEMPTS {
-                        error!("Request missing data (retry in 10s)");
+    // Changelog entries link to the profile of the user they mention
+    let links: Vec<String> = logins
+        .iter()
+        .map(|login| format!("(https://github.com/{})", login))
+        .collect();
 
-                        // Sleep for 10 seconds before trying again
-                        sleep(Duration::from_secs(10));
-                        continue;
-                    } else {
-                        return Err(SearchError::MissingData);
-                    }
+    let changelog_dir = cli.data_dir.join("changelog");
+    let mut changelogs = list_files(&changelog_dir)?;
+    for dir in changelogs.clone().into_iter().filter(|path| path.is_dir()) {
+        changelogs.extend(list_files(&dir)?);
+    }
+
+    for path in changelogs.into_iter().filter(|path| path.is_file()) {
+        let contents = read_to_string(&path).map_err(|err| LoaderError::ReadFile {
+            path: path.clone(),
+            err,
+        })?;
+        let lines: Vec<&str> = contents
+            .lines()
+            .filter(|line| !links.iter().any(|link| line.contains(link.as_str())))
+            .collect();
+        if lines.len() == contents.lines().count() {
+            continue;
+        }
+
+        info!("Removing opted out users from {}", path.display());
+        if !cli.dry_run {
+            write_file(&path, lines.join("\n") + "\n")?;
+        }
+    }
+
+    // Badges are stored as badges/{preset}/{metric}/{login}.svg
+    for preset in list_files(&cli.data_dir.join("badges"))? {
+        for metric in list_files(&preset)? {
+            for login in &logins {
+                let path = metric.join(format!("{}.svg", login));
+                if !path.exists() {
+                    continue;
                 }
-            };
 
-            let edges = match data.search.edges {
-                Some(ref value) if value.is_empty() => break 'outer,
-                Some(value) => value,
-                None => break 'outer,
-            };
+                info!("Removing badge {}", path.display());
+                if !cli.dry_run {
+                    let _ = remove_file(path);
+                }
+            }
+        }
+    }
 
-            edges
-                .into_iter()
-                .flatten()
-                .filter_map(|user| match user.node {
-                    Some(users_query::UsersQuerySearchEdgesNode::User(value)) => {
-                        Some((user.cursor, value))
-                    }
-                    _ => None,
-                })
-                // Skip blacklisted users
-                .filter(|(_, user)| {
-                    !blacklist
-                        .iter()
-                        .any(|blacklist| user.login.eq(blacklist.as_ref()))
-                })
-                .for_each(|(cursor, user)| {
-                    let contrib_count = user
-                        .contributions_collection
-                        .contribution_calendar
-                        .total_contributions;
-                    let priv_contrib_count =
-                        user.contributions_collection.restricted_contributions_count;
-                    let pub_contrib_count = contrib_count - priv_contrib_count;
-
-                    let orgs = if let Some(orgs) = user.organizations.nodes {
-                        orgs.into_iter()
-                            .flatten()
-                            .map(|value| value.login)
-                            .collect()
-                    } else {
-                        Vec::with_capacity(0)
-                    };
+    // Mirrored avatars are stored by node ID, or by login before users had IDs
+    logins.extend(ids);
+    remove_avatars(&cli.avatar_dir(), &logins, cli.dry_run)?;
 
-                    min_followers = user.followers.total_count;
-
-                    if !known_logins.contains(&user.login) {
-                        let user = User {
-                            login: user.login,
-                            avatar: user.avatar_url,
-                            name: user.name,
-                            company: user.company,
-                            orgs,
-                            followers: user.followers.total_count,
-                            contribs: contrib_count,
-                            pub_contribs: pub_contrib_count,
-                            priv_contribs: priv_contrib_count,
-                            commits: user.contributions_collection.total_commit_contributions,
-                            pull_requests: user
-                                .contributions_collection
-                                .total_pull_request_contributions,
-                        };
-                        known_logins.insert(user.login.clone());
-                        users.push(user);
-                    }
-                    last_cursor = Some(cursor);
-                });
+    Ok(())
+}
+
+/// Lists the paths of the entries in a directory, directories
+/// that don't exist are treated as empty
+///
+/// # Arguments
+/// * dir - The directory to list
+fn list_files(dir: &Path) -> Result<Vec<PathBuf>, LoaderError> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let read_err = |err| LoaderError::ReadFile {
+        path: dir.to_path_buf(),
+        err,
+    };
+    let mut paths = read_dir(dir)
+        .map_err(read_err)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(read_err)?;
+    paths.sort();
+    Ok(paths)
+}
+
+/// Prints the title, slug and number of locations of each preset
+fn list_presets() {
+    for preset in PRESETS {
+        println!(
+            "{} ({}): {} included, {} excluded",
+            preset.title,
+            preset.slug(),
+            preset.include.len(),
+            preset.exclude.len()
+        );
+    }
+}
+
+/// Checks every preset for problems such as duplicate locations and
+/// queries beyond the GitHub search limits. Presets with queries that
+/// are too long are only a problem if they can't be split into
+/// several queries that are within the limits
+fn validate_presets() -> Result<(), LoaderError> {
+    let mut invalid = 0;
+    let mut known_slugs = HashSet::new();
+
+    for preset in PRESETS {
+        let mut problems = Vec::new();
+
+        let slug = preset.slug();
+        if !known_slugs.insert(slug.clone()) {
+            problems.push(format!("Duplicate slug: {}", slug));
+        }
+
+        let mut known_locations = HashSet::new();
+        for location in preset.include.iter().chain(preset.exclude) {
+            if !known_locations.insert(location.to_lowercase()) {
+                problems.push(format!("Duplicate location: {}", location));
+            }
+
+            // Locations are encoded when building the query
+            if location.contains('+') || location.contains('"') {
+                problems.push(format!("Location is already encoded: {}", location));
+            }
+
+            if location.trim().is_empty() {
+                problems.push("Empty location".to_string());
+            }
+        }
+
+        let queries = split_preset(preset);
+        for query in &queries {
+            problems.extend(query_problems(query));
+        }
+
+        if problems.is_empty() {
+            if queries.len() > 1 {
+                println!(
+                    "{} ({}): OK, split into {} queries",
+                    preset.title,
+                    slug,
+                    queries.len()
+                );
+            }
+            continue;
+        }
+
+        invalid += 1;
+        for problem in problems {
+            error!("{} ({}): {}", preset.title, slug, problem);
+        }
+    }
+
+    if invalid > 0 {
+        return Err(LoaderError::InvalidPresets(invalid));
+    }
+
+    println!("All {} presets are valid", PRESETS.len());
+    Ok(())
+}
+
+/// Checks the current output for problems such as blacklisted or
+/// opted out users, duplicate users and incorrect ordering
+///
+/// # Arguments
+/// * cli - The command line options
+fn validate_output(cli: &Cli) -> Result<(), LoaderError> {
+    let blacklist = read_list(cli, &cli.blacklist)?;
+    let optout = read_list(cli, &cli.optout)?;
+    let private = read_list(cli, &cli.private_list)?;
+    let out = cli.output_path();
+    let output = read_output(&out)?;
+
+    let mut problems = 0;
+    let mut known_logins = HashSet::new();
+    let mut known_ids = HashSet::new();
+
+    if let Err(err) = validate_schema(&output) {
+        error!("{}", err);
+        problems += 1;
+    }
+
+    for (index, user) in output.users.iter().enumerate() {
+        if !known_logins.insert(&user.login) {
+            error!("Duplicate user: {}", user.login);
+            problems += 1;
+        }
+
+        if let Some(id) = &user.id {
+            if !known_ids.insert(id) {
+                error!("Duplicate account ID: {} ({})", id, user.login);
+                problems += 1;
+            }
+        }
+
+        if user.is_listed(&blacklist) {
+            error!("Blacklisted user: {}", user.login);
+            problems += 1;
+        }
+
+        if user.is_listed(&optout) {
+            error!("Opted out user: {}", user.login);
+            problems += 1;
+        }
 
-            debug!("Progress: {}/{}", users.len(), USERS);
+        if user.priv_contribs.is_some() && user.is_listed(&private) {
+            error!("Private contributions not hidden for: {}", user.login);
+            problems += 1;
+        }
 
-            if users.len() >= USERS {
-                users.truncate(USERS);
-                break 'outer;
+        if let Some(previous) = index.checked_sub(1).map(|index| &output.users[index]) {
+            if previous.commits < user.commits {
+                error!(
+                    "User {} is ranked below {} with more commits",
+                    user.login, previous.login
+                );
+                problems += 1;
             }
         }
     }
 
-    Ok((users, min_followers))
+    if problems > 0 {
+        return Err(LoaderError::InvalidOutput {
+            path: out,
+            problems,
+        });
+    }
+
+    println!("{} is valid ({} users)", out.display(), output.users.len());
+    Ok(())
+}
+
+/// Provides the output file paths for the provided preset, the
+/// default preset is written to each of the output paths while all
+/// others are written to presets/{slug}.json in the data directory
+///
+/// # Arguments
+/// * cli - The command line options
+/// * preset - The preset to get the paths for
+fn output_paths(cli: &Cli, preset: &LocationPreset) -> Vec<PathBuf> {
+    if ptr::eq(preset, PRESET) {
+        cli.output_paths()
+    } else {
+        vec![snapshot_path(cli, preset)]
+    }
+}
+
+/// Provides the path of the stored snapshot for the provided preset
+///
+/// # Arguments
+/// * cli - The command line options
+/// * preset - The preset to get the path for
+fn snapshot_path(cli: &Cli, preset: &LocationPreset) -> PathBuf {
+    if ptr::eq(preset, PRESET) {
+        cli.output_path()
+    } else {
+        cli.data_dir
+            .join("presets")
+            .join(format!("{}.json", preset.slug()))
+    }
+}
+
+/// Reads the previously stored snapshot for the provided preset,
+/// snapshots that fail to read are logged and ignored
+///
+/// # Arguments
+/// * cli - The command line options
+/// * preset - The preset to read the snapshot for
+/// * excluded - List of names to remove from the snapshot
+/// * privacy - The settings for redacting private contributions
+fn read_previous(
+    cli: &Cli,
+    preset: &LocationPreset,
+    excluded: &[Box<str>],
+    privacy: &Privacy,
+) -> Option<Output> {
+    let path = snapshot_path(cli, preset);
+    if !path.exists() {
+        return None;
+    }
+
+    match read_snapshot(&path, excluded, privacy) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            warn!("Ignoring previous snapshot: {}", err);
+            None
+        }
+    }
+}
+
+/// Writes the changelog between the previous and current snapshot of
+/// a preset to changelog/{date}.md in the data directory for the
+/// default preset and changelog/{slug}/{date}.md for all others
+///
+/// # Arguments
+/// * cli - The command line options
+/// * preset - The preset the snapshots are for
+/// * previous - The previous snapshot
+/// * current - The current snapshot
+fn write_changelog(
+    cli: &Cli,
+    preset: &LocationPreset,
+    previous: &Output,
+    current: &Output,
+) -> Result<(), LoaderError> {
+    let mut path = cli.data_dir.join("changelog");
+    if !ptr::eq(preset, PRESET) {
+        path.push(preset.slug());
+    }
+    path.push(format!("{}.md", current.generated_at.date_naive()));
+
+    write_file(&path, render_changelog(previous, current))?;
+    debug!("Wrote changelog to {}", path.display());
+    Ok(())
+}
+
+/// Creates the output for the provided users, preset
+/// and min followers
+///
+/// # Arguments
+/// * users - The collection of users
+/// * preset - The preset the users were collected for
+/// * min_followers - The min follower count
+fn create_output(mut users: Vec<User>, preset: &LocationPreset, min_followers: i64) -> Output {
+    // Sort the results by number of commits
+    users.sort_by_key(|user| Reverse(user.commits));
+
+    Output {
+        schema_version: SCHEMA_VERSION,
+        title: preset.title.to_string(),
+        min_followers,
+        generated_at: Utc::now(),
+        stats: Stats::from_users(&users),
+        organizations: rank_organizations(&users),
+        companies: rank_companies(&users),
+        languages: rank_languages(&users),
+        users,
+    }
+}
+
+/// Writes the provided output to each of the provided paths
+///
+/// # Arguments
+/// * paths - The paths of the files to write
+/// * output - The output to write
+fn write_outputs(paths: &[PathBuf], output: &Output) -> Result<(), LoaderError> {
+    for path in paths {
+        write_output(path, output).map_err(|err| LoaderError::Output {
+            path: path.clone(),
+            err,
+        })?;
+        debug!("Wrote output to {}", path.display());
+    }
+    Ok(())
+}
+
+/// Reads the settings for redacting private contributions
+///
+/// # Arguments
+/// * cli - The command line options
+fn read_privacy(cli: &Cli) -> Result<Privacy, LoaderError> {
+    Ok(Privacy {
+        mode: cli.private_contribs,
+        hidden: read_list(cli, &cli.private_list)?,
+    })
+}
+
+/// Reads a stored snapshot with any opted out users removed and the
+/// private contributions redacted following the privacy settings
+///
+/// # Arguments
+/// * path - The path of the snapshot
+/// * optout - List of opted out names
+/// * privacy - The settings for redacting private contributions
+fn read_snapshot(
+    path: &Path,
+    optout: &[Box<str>],
+    privacy: &Privacy,
+) -> Result<Output, LoaderError> {
+    let mut output = read_output(path)?;
+    output.remove_users(optout);
+    privacy.apply(&mut output.users);
+    Ok(output)
+}
+
+/// Resolves the logins in the list files that haven't been resolved
+/// yet to the node IDs of their users and stores them, so that the
+/// entries keep matching their users after they rename
+///
+/// # Arguments
+/// * cli - The command line options
+/// * client - The client to make the graphql requests
+/// * limiter - The shared rate limiter
+/// * batcher - The batch fetcher to look up the users with
+fn resolve_identities(
+    cli: &Cli,
+    client: &GitHubClient,
+    limiter: &RateLimiter,
+    batcher: &BatchFetcher,
+) -> Result<(), LoaderError> {
+    let mut identities = Identities::read(&cli.identities_path())?;
+    let mut names = read_names(&cli.blacklist)?;
+    names.extend(read_names(&cli.optout)?);
+    names.extend(read_names(&cli.private_list)?);
+
+    let logins = identities.unresolved(&names);
+    if logins.is_empty() || cli.dry_run {
+        return Ok(());
+    }
+
+    info!("Resolving {} listed login(s) to account IDs", logins.len());
+    let fetched = fetch_users(client, limiter, batcher, Lookup::Login, &logins);
+
+    // Users that couldn't be fetched are looked up again by the next crawl
+    let resolved = fetched.users.len() + fetched.missing.len();
+    for (login, user) in fetched.users {
+        identities.insert(login, user.id);
+    }
+    for login in fetched.missing {
+        identities.insert(login, None);
+    }
+
+    if resolved > 0 {
+        identities.save()?;
+    }
+    Ok(())
+}
+
+/// Reads the names in a list file along with the node IDs their
+/// logins resolved to
+///
+/// # Arguments
+/// * cli - The command line options
+/// * path - The path of the list file
+fn read_list(cli: &Cli, path: &Path) -> Result<Vec<Box<str>>, LoaderError> {
+    let identities = Identities::read(&cli.identities_path())?;
+    Ok(identities.expand(read_names(path)?))
+}
+
+/// Reads the collection of names from a list file such as the
+/// blacklist or opt-out list (One login, node ID or prefixed
+/// database ID per line)
+///
+/// # Arguments
+/// * path - The path of the list file
+fn read_names(path: &Path) -> Result<Vec<Box<str>>, LoaderError> {
+    if !path.exists() {
+        return Ok(Vec::with_capacity(0));
+    }
+
+    let file = read_to_string(path).map_err(|err| LoaderError::ReadFile {
+        path: path.to_path_buf(),
+        err,
+    })?;
+    Ok(file
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(Box::from)
+        .collect())
 }
@@ -0,0 +1,624 @@
+use std::{
+    cmp::Reverse,
+    fs::{create_dir_all, read_to_string, remove_file, rename, File},
+    io::Write,
+    path::{Path, PathBuf},
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        OnceLock,
+    },
+};
+
+use chrono::prelude::*;
+use clap::ValueEnum;
+use jsonschema::JSONSchema;
+use schemars::{schema::RootSchema, schema_for, JsonSchema};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::{
+    groups::{rank_companies, rank_organizations, Group},
+    languages::{rank_languages, LanguageBoard, LanguageShare},
+    privacy::Band,
+    stats::Stats,
+};
+
+/// Current version of the output format, this should be increased
+/// whenever the shape of the output changes and a migration for
+/// the previous version added to [`migrate`]
+pub const SCHEMA_VERSION: u32 = 8;
+
+/// Prefix of list entries naming a user by their database ID, which
+/// keeps an all digit login from matching an unrelated account
+pub const DATABASE_ID_PREFIX: &str = "database_id:";
+
+/// Snapshot of the top committers for a location preset
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct Output {
+    /// Version of the output format
+    pub schema_version: u32,
+    /// Title of the location preset
+    pub title: String,
+    /// Number of followers required to be included in the list
+    pub min_followers: i64,
+    /// When the snapshot was generated
+    pub generated_at: DateTime<Utc>,
+    /// Aggregate statistics for the users
+    pub stats: Stats,
+    /// Organizations ranked by the combined commits of their members
+    pub organizations: Vec<Group>,
+    /// Companies ranked by the combined commits of their members
+    pub companies: Vec<Group>,
+    /// Languages ranked by the estimated commits made in them, empty
+    /// unless the languages of the users were fetched
+    pub languages: Vec<LanguageBoard>,
+    /// The users sorted by their number of commits
+    pub users: Vec<User>,
+}
+
+/// A GitHub user and their contributions for the last year
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct User {
+    /// GitHub node ID, which unlike the login never changes. Missing
+    /// for older snapshots
+    pub id: Option<String>,
+    /// GitHub database ID, missing for older snapshots
+    pub database_id: Option<i64>,
+    /// GitHub username
+    pub login: String,
+    /// Logins the user was previously seen under, oldest first
+    pub previous_logins: Vec<String>,
+    /// URL of the user avatar
+    pub avatar: String,
+    /// Display name of the user
+    pub name: Option<String>,
+    /// Company from the user profile
+    pub company: Option<String>,
+    /// Bio from the user profile
+    pub bio: Option<String>,
+    /// Website from the user profile
+    pub website: Option<String>,
+    /// Twitter username from the user profile
+    pub twitter: Option<String>,
+    /// Social accounts linked on the user profile
+    pub social_accounts: Vec<SocialAccount>,
+    /// When the account was created, missing for older snapshots
+    pub created_at: Option<DateTime<Utc>>,
+    /// Whether the user is available for hire, missing for older snapshots
+    pub hireable: Option<bool>,
+    /// Whether the user has a GitHub Sponsors listing, missing
+    /// for older snapshots
+    pub sponsors_listing: Option<bool>,
+    /// Logins of the organizations the user is a member of
+    pub orgs: Vec<String>,
+    /// Total number of organizations the user is a member of
+    pub orgs_total: i64,
+    /// Top languages the user committed in, empty unless the
+    /// languages were fetched
+    pub languages: Vec<LanguageShare>,
+    /// Number of followers
+    pub followers: i64,
+    /// Total number of contributions, missing when the private
+    /// contributions are redacted
+    pub contribs: Option<i64>,
+    /// Number of public contributions
+    pub pub_contribs: i64,
+    /// Number of private contributions, missing when redacted
+    pub priv_contribs: Option<i64>,
+    /// Range the private contributions fall within when they
+    /// are redacted into ranges
+    pub priv_contribs_band: Option<Band>,
+    /// Number of commits
+    pub commits: i64,
+    /// Number of pull requests
+    pub pull_requests: i64,
+}
+
+/// A social account linked on a user profile
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SocialAccount {
+    /// Lowercase name of the provider hosting the account (e.g. "mastodon")
+    pub provider: String,
+    /// URL of the account
+    pub url: String,
+    /// Name of the account as it appears on the profile
+    pub display_name: String,
+}
+
+impl User {
+    /// Checks whether the user is in the provided list of names, names can
+    /// be the current or a previous login of the user, their node ID or
+    /// their database ID after [`DATABASE_ID_PREFIX`] so that entries keep
+    /// working when users rename
+    ///
+    /// # Arguments
+    /// * names - The names to check
+    pub fn is_listed(&self, names: &[Box<str>]) -> bool {
+        names.iter().any(|name| {
+            let name = name.as_ref();
+            self.login == name
+                || self.previous_logins.iter().any(|login| login == name)
+                || self.id.as_deref() == Some(name)
+                || name
+                    .strip_prefix(DATABASE_ID_PREFIX)
+                    .zip(self.database_id)
+                    .is_some_and(|(name, id)| id.to_string() == name)
+        })
+    }
+
+    /// Checks whether the other user is the same GitHub account, users are
+    /// matched by their node ID when both have one and by login otherwise
+    ///
+    /// # Arguments
+    /// * other - The user to compare with
+    pub fn is_same_account(&self, other: &User) -> bool {
+        match (&self.id, &other.id) {
+            (Some(id), Some(other_id)) => id == other_id,
+            _ => self.login == other.login,
+        }
+    }
+}
+
+impl Output {
+    /// Recomputes the statistics and leaderboards that are
+    /// derived from the users
+    pub fn update_aggregates(&mut self) {
+        self.stats = Stats::from_users(&self.users);
+        self.organizations = rank_organizations(&self.users);
+        self.companies = rank_companies(&self.users);
+        self.languages = rank_languages(&self.users);
+    }
+
+    /// Removes the users that are in the provided list of names,
+    /// recomputing the aggregates when any are removed. Provides
+    /// the removed users
+    ///
+    /// # Arguments
+    /// * names - The names of the users to remove
+    pub fn remove_users(&mut self, names: &[Box<str>]) -> Vec<User> {
+        let (removed, kept): (Vec<User>, Vec<User>) = std::mem::take(&mut self.users)
+            .into_iter()
+            .partition(|user| user.is_listed(names));
+        self.users = kept;
+
+        if !removed.is_empty() {
+            self.update_aggregates();
+        }
+        removed
+    }
+
+    /// Carries the previous logins of the users over from an earlier
+    /// snapshot, recording the old login of users that renamed since.
+    /// Provides the old and new logins of the renamed users
+    ///
+    /// # Arguments
+    /// * previous - The earlier snapshot
+    pub fn track_renames(&mut self, previous: &Output) -> Vec<(String, String)> {
+        let mut renames = Vec::new();
+        for user in &mut self.users {
+            let Some(previous_user) = previous
+                .users
+                .iter()
+                .find(|previous_user| previous_user.is_same_account(user))
+            else {
+                continue;
+            };
+
+            let mut previous_logins = previous_user.previous_logins.clone();
+            if previous_user.login != user.login {
+                renames.push((previous_user.login.clone(), user.login.clone()));
+                previous_logins.push(previous_user.login.clone());
+            }
+
+            // A login that was taken back is no longer a previous login
+            previous_logins.retain(|login| *login != user.login);
+            user.previous_logins = previous_logins;
+        }
+        renames
+    }
+}
+
+/// User statistics that users can be ranked by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Metric {
+    Commits,
+    Contributions,
+    PublicContributions,
+    PullRequests,
+    Followers,
+}
+
+impl Metric {
+    /// Provides the value of the metric for the provided user
+    pub fn value(&self, user: &User) -> i64 {
+        match self {
+            Metric::Commits => user.commits,
+            // Boards with redacted users are ranked by public contributions
+            // instead, see [`Metric::ranked_by`]
+            Metric::Contributions => user.contribs.unwrap_or(user.pub_contribs),
+            Metric::PublicContributions => user.pub_contribs,
+            Metric::PullRequests => user.pull_requests,
+            Metric::Followers => user.followers,
+        }
+    }
+
+    /// Human readable name of the metric
+    pub fn label(&self) -> &'static str {
+        match self {
+            Metric::Commits => "Commits",
+            Metric::Contributions => "Contributions",
+            Metric::PublicContributions => "Public Contributions",
+            Metric::PullRequests => "Pull Requests",
+            Metric::Followers => "Followers",
+        }
+    }
+
+    /// File name safe name of the metric
+    pub fn slug(&self) -> &'static str {
+        match self {
+            Metric::Commits => "commits",
+            Metric::Contributions => "contributions",
+            Metric::PublicContributions => "public-contributions",
+            Metric::PullRequests => "pull-requests",
+            Metric::Followers => "followers",
+        }
+    }
+
+    /// Provides the metric the users are ranked by for this metric, the
+    /// contributions of boards with any redacted users are ranked by the
+    /// public contributions so that public and total counts aren't compared
+    ///
+    /// # Arguments
+    /// * users - The users on the board
+    pub fn ranked_by(&self, users: &[User]) -> Metric {
+        match self {
+            Metric::Contributions if users.iter().any(|user| user.contribs.is_none()) => {
+                Metric::PublicContributions
+            }
+            metric => *metric,
+        }
+    }
+
+    /// Provides the users ranked from highest to lowest by this metric
+    ///
+    /// # Arguments
+    /// * users - The users to rank
+    pub fn rank<'a>(&self, users: &'a [User]) -> Vec<&'a User> {
+        let metric = self.ranked_by(users);
+        let mut ranked: Vec<&User> = users.iter().collect();
+        ranked.sort_by_key(|user| Reverse(metric.value(user)));
+        ranked
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum OutputResult {
+    #[error("Error while serializing results: {0}")]
+    Serialize(#[from] serde_json::Error),
+    #[error("Failed to create/write output file: {0}")]
+    WriteFile(#[from] std::io::Error),
+    #[error("Output does not match the output schema: {0}")]
+    Invalid(String),
+    #[error("Failed to read {path}: {err}")]
+    ReadFile { path: PathBuf, err: std::io::Error },
+    #[error("{path} is corrupt: {err}")]
+    CorruptFile {
+        path: PathBuf,
+        err: serde_json::Error,
+    },
+    #[error(
+        "{path} uses schema version {version} which is newer than the supported version {}",
+        SCHEMA_VERSION
+    )]
+    UnsupportedSchema { path: PathBuf, version: u32 },
+}
+
+/// Creates the JSON Schema describing the output format
+pub fn output_schema() -> RootSchema {
+    schema_for!(Output)
+}
+
+/// Validates the provided output against the output schema
+///
+/// # Arguments
+/// * output - The output to validate
+pub fn validate_schema(output: &Output) -> Result<(), OutputResult> {
+    static VALIDATOR: OnceLock<JSONSchema> = OnceLock::new();
+
+    let validator = VALIDATOR.get_or_init(|| {
+        let schema =
+            serde_json::to_value(output_schema()).expect("Output schema should be serializable");
+        JSONSchema::compile(&schema).expect("Output schema should be a valid JSON Schema")
+    });
+
+    let value = serde_json::to_value(output)?;
+    if let Err(errors) = validator.validate(&value) {
+        let errors: Vec<String> = errors
+            .map(|error| format!("{} at {}", error, error.instance_path))
+            .collect();
+        return Err(OutputResult::Invalid(errors.join(", ")));
+    }
+
+    Ok(())
+}
+
+/// Validates, serializes and writes the provided output to a file
+///
+/// # Arguments
+/// * path - The path of the file to write
+/// * output - The output to write
+pub fn write_output(path: &Path, output: &Output) -> Result<(), OutputResult> {
+    validate_schema(output)?;
+
+    let json: String = serde_json::to_string(output)?;
+    write_atomic(path, json.as_bytes())?;
+
+    Ok(())
+}
+
+/// Writes a file through a temporary file which then replaces the existing
+/// file so that a failed write never leaves a truncated file behind. Each
+/// write uses its own temporary file so that workers writing the same file
+/// at the same time don't clash
+///
+/// # Arguments
+/// * path - The path of the file to write
+/// * contents - The contents to write
+pub fn write_atomic(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    static TEMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(format!(
+        ".{}-{}.tmp",
+        process::id(),
+        TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let temp = PathBuf::from(temp);
+
+    let result = File::create(&temp).and_then(|mut file| {
+        file.write_all(contents)?;
+        file.sync_all()
+    });
+    if let Err(err) = result.and_then(|_| rename(&temp, path)) {
+        let _ = remove_file(&temp);
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+/// Reads and parses a previously written output file, migrating
+/// outputs written with an older schema version
+///
+/// # Arguments
+/// * path - The path of the file to read
+pub fn read_output(path: &Path) -> Result<Output, OutputResult> {
+    let corrupt = |err| OutputResult::CorruptFile {
+        path: path.to_path_buf(),
+        err,
+    };
+
+    let json = read_to_string(path).map_err(|err| OutputResult::ReadFile {
+        path: path.to_path_buf(),
+        err,
+    })?;
+    let mut value: Value = serde_json::from_str(&json).map_err(corrupt)?;
+
+    // Outputs written before versioning have no version field
+    let version = value
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .unwrap_or(0) as u32;
+
+    if version > SCHEMA_VERSION {
+        return Err(OutputResult::UnsupportedSchema {
+            path: path.to_path_buf(),
+            version,
+        });
+    }
+
+    migrate(&mut value, version);
+
+    serde_json::from_value(value).map_err(corrupt)
+}
+
+/// Migrates an output value from the provided schema version
+/// to the current schema version
+///
+/// # Arguments
+/// * value - The output value to migrate
+/// * version - The schema version of the value
+fn migrate(value: &mut Value, version: u32) {
+    let Some(object) = value.as_object_mut() else {
+        return;
+    };
+
+    // Fields added to users are filled in first so that the users
+    // can be read to recompute the values derived from them
+    let users = object.get_mut("users").and_then(Value::as_array_mut);
+    for user in users.into_iter().flatten().filter_map(Value::as_object_mut) {
+        if version < 4 {
+            // Version 4 added the total organization count, older outputs
+            // only have the first page so the true count isn't known
+            let orgs = user
+                .get("orgs")
+                .and_then(Value::as_array)
+                .map_or(0, Vec::len);
+            user.insert("orgs_total".to_string(), orgs.into());
+        }
+
+        if version < 5 {
+            // Version 5 added the profile metadata, the optional fields
+            // are left missing until the user is next crawled
+            user.insert("social_accounts".to_string(), Value::Array(Vec::new()));
+        }
+
+        if version < 6 {
+            // Version 6 added the languages of the users
+            user.insert("languages".to_string(), Value::Array(Vec::new()));
+        }
+
+        if version < 8 {
+            // Version 8 added the account IDs, which are left missing until
+            // the user is next crawled, and the logins renamed from
+            user.insert("previous_logins".to_string(), Value::Array(Vec::new()));
+        }
+
+        // Version 7 allowed the private contributions to be redacted, older
+        // outputs always have the exact counts so need no changes
+    }
+
+    // Version 2 added the aggregate statistics and version 3 added the
+    // organization and company leaderboards and version 6 added the
+    // language leaderboards, these are always recomputed
+    let users: Vec<User> = object
+        .get("users")
+        .cloned()
+        .and_then(|users| serde_json::from_value(users).ok())
+        .unwrap_or_default();
+
+    let derived = [
+        ("stats", serde_json::to_value(Stats::from_users(&users))),
+        (
+            "organizations",
+            serde_json::to_value(rank_organizations(&users)),
+        ),
+        ("companies", serde_json::to_value(rank_companies(&users))),
+        ("languages", serde_json::to_value(rank_languages(&users))),
+    ];
+    for (key, value) in derived {
+        object.insert(
+            key.to_string(),
+            value.expect("Derived values should be serializable"),
+        );
+    }
+
+    object.insert("schema_version".to_string(), SCHEMA_VERSION.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    /// Output in the format written before the schema was versioned
+    fn unversioned_output() -> Value {
+        json!({
+            "title": "New Zealand",
+            "min_followers": 10,
+            "generated_at": "2024-01-01T00:00:00Z",
+            "users": [{
+                "login": "octocat",
+                "avatar": "https://avatars.githubusercontent.com/u/1?v=4",
+                "name": "Octocat",
+                "company": "@github",
+                "orgs": ["github", "octo-org"],
+                "followers": 100,
+                "contribs": 500,
+                "pub_contribs": 400,
+                "priv_contribs": 100,
+                "commits": 300,
+                "pull_requests": 20
+            }]
+        })
+    }
+
+    #[test]
+    fn migrate_unversioned_output() {
+        let mut value = unversioned_output();
+        migrate(&mut value, 0);
+
+        let output: Output = serde_json::from_value(value).unwrap();
+        assert_eq!(output.schema_version, SCHEMA_VERSION);
+        assert_eq!(output.stats.users, 1);
+        assert_eq!(output.organizations.len(), 2);
+        assert_eq!(output.companies.len(), 1);
+
+        let user = &output.users[0];
+        assert_eq!(user.orgs_total, 2);
+        assert!(user.social_accounts.is_empty());
+        assert!(user.languages.is_empty());
+        assert!(user.previous_logins.is_empty());
+        assert_eq!(user.id, None);
+        assert_eq!(user.contribs, Some(500));
+        assert_eq!(user.priv_contribs, Some(100));
+        validate_schema(&output).unwrap();
+    }
+
+    #[test]
+    fn migrate_keeps_newer_fields() {
+        let mut value = unversioned_output();
+        let user = &mut value["users"][0];
+        user["orgs_total"] = json!(150);
+        user["id"] = json!("MDQ6VXNlcjE=");
+        user["database_id"] = json!(1);
+        user["social_accounts"] = json!([]);
+        user["languages"] = json!([{ "name": "Rust", "share": 1.0 }]);
+        migrate(&mut value, 7);
+
+        let output: Output = serde_json::from_value(value).unwrap();
+        let user = &output.users[0];
+        assert_eq!(user.orgs_total, 150);
+        assert_eq!(user.id.as_deref(), Some("MDQ6VXNlcjE="));
+        assert_eq!(output.languages.len(), 1);
+    }
+
+    #[test]
+    fn migrate_current_output_is_unchanged() {
+        let mut value = unversioned_output();
+        migrate(&mut value, 0);
+        let migrated = value.clone();
+        migrate(&mut value, SCHEMA_VERSION);
+        assert_eq!(value, migrated);
+    }
+
+    #[test]
+    fn listed_by_login_or_id() {
+        let mut value = unversioned_output();
+        let user = &mut value["users"][0];
+        user["id"] = json!("MDQ6VXNlcjE=");
+        user["database_id"] = json!(1);
+        migrate(&mut value, 0);
+
+        let mut output: Output = serde_json::from_value(value).unwrap();
+        let user = &mut output.users[0];
+        user.previous_logins.push("octo".to_string());
+        let listed = |name: &str| user.is_listed(&[Box::from(name)]);
+        assert!(listed("octocat"));
+        assert!(listed("octo"));
+        assert!(listed("MDQ6VXNlcjE="));
+        assert!(listed("database_id:1"));
+        // A bare number is a login, not a database ID
+        assert!(!listed("1"));
+        assert!(!listed("database_id:2"));
+    }
+
+    #[test]
+    fn redacted_boards_rank_public_contributions() {
+        let mut value = unversioned_output();
+        let mut redacted = value["users"][0].clone();
+        redacted["login"] = json!("redacted");
+        redacted["pub_contribs"] = json!(450);
+        value["users"].as_array_mut().unwrap().push(redacted);
+        migrate(&mut value, 0);
+
+        let mut output: Output = serde_json::from_value(value).unwrap();
+        let ranked = Metric::Contributions.rank(&output.users);
+        assert_eq!(ranked[0].login, "octocat");
+
+        output.users[1].contribs = None;
+        assert_eq!(
+            Metric::Contributions.ranked_by(&output.users),
+            Metric::PublicContributions
+        );
+        let ranked = Metric::Contributions.rank(&output.users);
+        assert_eq!(ranked[0].login, "redacted");
+    }
+}
@@ -0,0 +1,95 @@
+use std::fmt::{self, Display};
+
+use clap::ValueEnum;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::output::User;
+
+/// Lower bounds of the ranges private contributions are banded into
+const PRIVATE_BANDS: &[i64] = &[0, 1, 100, 250, 500, 1000, 2500, 5000, 10000];
+
+/// How the private contributions of users are included in the output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum PrivateContribs {
+    /// Include the exact number of private contributions
+    Exact,
+    /// Include the range the private contributions fall within
+    Banded,
+    /// Leave out the private contributions
+    Hidden,
+}
+
+/// Range of values that a redacted value falls within
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Band {
+    /// Inclusive lower bound of the range
+    pub min: i64,
+    /// Inclusive upper bound of the range, missing for the last range
+    pub max: Option<i64>,
+}
+
+impl Band {
+    /// Finds the band of private contributions containing the provided value
+    ///
+    /// # Arguments
+    /// * value - The value to find the band of
+    fn of(value: i64) -> Self {
+        let index = PRIVATE_BANDS
+            .iter()
+            .rposition(|&min| min <= value)
+            .unwrap_or_default();
+        Self {
+            min: PRIVATE_BANDS[index],
+            max: PRIVATE_BANDS.get(index + 1).map(|next| next - 1),
+        }
+    }
+}
+
+impl Display for Band {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.max {
+            Some(max) if max == self.min => write!(f, "{}", self.min),
+            Some(max) => write!(f, "{}-{}", self.min, max),
+            None => write!(f, "{}+", self.min),
+        }
+    }
+}
+
+/// Settings for redacting the private contributions of users
+pub struct Privacy {
+    /// How the private contributions of all users are included
+    pub mode: PrivateContribs,
+    /// Names of users whose private contributions are always hidden
+    pub hidden: Vec<Box<str>>,
+}
+
+impl Privacy {
+    /// Redacts the private contributions of the provided users, the total
+    /// contributions are removed along with them as the private count could
+    /// be worked out from them
+    ///
+    /// # Arguments
+    /// * users - The users to redact
+    pub fn apply(&self, users: &mut [User]) {
+        for user in users {
+            let mode = if user.is_listed(&self.hidden) {
+                PrivateContribs::Hidden
+            } else {
+                self.mode
+            };
+
+            match (mode, user.priv_contribs) {
+                // Users that were already redacted can't be restored
+                (PrivateContribs::Exact, _) | (PrivateContribs::Banded, None) => continue,
+                (PrivateContribs::Banded, Some(priv_contribs)) => {
+                    user.priv_contribs_band = Some(Band::of(priv_contribs));
+                }
+                (PrivateContribs::Hidden, _) => user.priv_contribs_band = None,
+            }
+
+            user.priv_contribs = None;
+            user.contribs = None;
+        }
+    }
+}
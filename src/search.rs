@@ -0,0 +1,787 @@
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    thread::sleep,
+    time::Duration,
+};
+
+use chrono::prelude::*;
+use graphql_client::{GraphQLQuery, Response};
+use log::{debug, error, warn};
+use thiserror::Error;
+
+use crate::{
+    batch::{BatchFetcher, Batched, Lookup, UserFragment},
+    client::GitHubClient,
+    languages::{language_shares, LanguageShare},
+    limiter::RateLimiter,
+    output::{SocialAccount, User},
+    preset::LocationPreset,
+};
+
+#[allow(clippy::upper_case_acronyms)]
+type URI = String;
+type DateTime = chrono::DateTime<Utc>;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/schema.graphql",
+    query_path = "src/users.graphql",
+    response_derives = "Debug"
+)]
+struct UsersQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/schema.graphql",
+    query_path = "src/organizations.graphql",
+    response_derives = "Debug"
+)]
+struct UserOrganizationsQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/schema.graphql",
+    query_path = "src/languages.graphql",
+    response_derives = "Debug"
+)]
+#[allow(dead_code)] // Only the fragment is used, in batches
+struct UserLanguagesQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/schema.graphql",
+    query_path = "src/user_count.graphql",
+    response_derives = "Debug"
+)]
+struct UserCountQuery;
+
+/// The number of users to collect (Considered amount)
+pub const USERS: usize = 1000;
+
+/// Number of users requested in each page
+pub const PER_PAGE: usize = 5;
+
+/// Maximum number of results GitHub provides for a single search query
+const MAX_PER_QUERY: usize = 1000;
+
+/// Maximum length of a search query including its qualifiers. GitHub's
+/// documented 256 character limit doesn't count qualifiers, which make up
+/// the whole of a generated query, but far longer queries are rejected.
+/// The New Zealand query, at over 350 characters, has always been accepted
+pub const MAX_QUERY_LENGTH: usize = 1000;
+
+/// Follower count used when checking the length of queries, leaving
+/// room for the follower restriction added while paging
+const FOLLOWERS_PLACEHOLDER: i64 = 9_999_999;
+
+/// Maximum number of times a request can retry before failing
+pub const MAX_ATTEMPTS: usize = 10;
+
+/// Number of rate limit points spent by each request
+pub const REQUEST_COST: u32 = 1;
+
+/// User fields fetched for each user in a batch
+pub const USER_FIELDS: UserFragment = UserFragment {
+    name: "UserFields",
+    document: users_query::QUERY,
+    cost: 1,
+};
+
+/// Languages fetched for each user in a batch, each user requests
+/// the languages of up to 25 repositories
+pub const USER_LANGUAGES: UserFragment = UserFragment {
+    name: "UserLanguages",
+    document: user_languages_query::QUERY,
+    cost: 3,
+};
+
+/// Errors that could occur while searching for users
+#[derive(Debug, Error)]
+pub enum SearchError {
+    #[error("Ran out of attempts and failed request")]
+    FailedRequest(#[from] reqwest::Error),
+    #[error("Request encountered errors")]
+    RequestErrors,
+    #[error("Request missing data")]
+    MissingData,
+}
+
+/// Searches for and collects users from GitHub, presets with queries
+/// beyond the GitHub search limits are split into several queries with
+/// the users with the most followers across them being kept
+///
+/// # Arguments
+/// * client - The client to make the graphql requests
+/// * limiter - The shared rate limiter
+/// * blacklist - List of blacklisted names
+/// * location - The location data for the request
+pub fn search_users(
+    client: &GitHubClient,
+    limiter: &RateLimiter,
+    blacklist: &[Box<str>],
+    location: &LocationPreset,
+) -> Result<(Vec<User>, i64), SearchError> {
+    let mut known_logins = HashSet::new();
+
+    let queries = split_preset(location);
+    if let [query] = queries.as_slice() {
+        return collect_users(client, limiter, blacklist, query, None, &mut known_logins);
+    }
+
+    debug!("{}: Split into {} queries", location.title, queries.len());
+
+    let mut users: Vec<User> = Vec::new();
+    for query in &queries {
+        // Once enough users are merged, users with fewer followers than the
+        // last of the kept users would be dropped so they aren't collected
+        let floor = (users.len() >= USERS).then(|| {
+            users.sort_by_key(|user| Reverse(user.followers));
+            users[USERS - 1].followers
+        });
+
+        let (found, _) =
+            collect_users(client, limiter, blacklist, query, floor, &mut known_logins)?;
+        users.extend(found);
+    }
+
+    users.sort_by_key(|user| Reverse(user.followers));
+    users.truncate(USERS);
+
+    let min_followers = users.iter().map(|user| user.followers).min().unwrap_or(-1);
+    Ok((users, min_followers))
+}
+
+/// Collects the users matching the query of a location preset, users
+/// already in the known logins are skipped
+///
+/// # Arguments
+/// * client - The client to make the graphql requests
+/// * limiter - The shared rate limiter
+/// * blacklist - List of blacklisted names
+/// * location - The location data for the request
+/// * floor - Follower count that collecting stops below
+/// * known_logins - The logins of the users that were already collected
+fn collect_users(
+    client: &GitHubClient,
+    limiter: &RateLimiter,
+    blacklist: &[Box<str>],
+    location: &LocationPreset,
+    floor: Option<i64>,
+    known_logins: &mut HashSet<String>,
+) -> Result<(Vec<User>, i64), SearchError> {
+    let mut users: Vec<User> = Vec::new();
+    let mut last_cursor: Option<String> = None;
+
+    let mut min_followers = -1;
+
+    // Users with more organizations than the first page, stored as the
+    // index of the user and the cursor of the next page
+    let mut pending_orgs: Vec<(usize, String)> = Vec::new();
+
+    'outer: while users.len() < USERS {
+        let query = build_query(location, (min_followers >= 0).then_some(min_followers));
+
+        for _ in 1..(MAX_PER_QUERY / PER_PAGE) {
+            let after = last_cursor.take();
+            let data = request::<UsersQuery>(client, limiter, || users_query::Variables {
+                query: query.clone(),
+                first: PER_PAGE as i64,
+                after: after.clone(),
+            })?;
+
+            let edges = match data.search.edges {
+                Some(ref value) if value.is_empty() => break 'outer,
+                Some(value) => value,
+                None => break 'outer,
+            };
+
+            edges
+                .into_iter()
+                .flatten()
+                .filter_map(|user| match user.node {
+                    Some(users_query::UsersQuerySearchEdgesNode::User(value)) => {
+                        Some((user.cursor, value))
+                    }
+                    _ => None,
+                })
+                .map(|(cursor, user)| (cursor, create_user(user)))
+                // Skip blacklisted users, matching their IDs as well as
+                // their login so that renamed users stay blacklisted
+                .filter(|(_, (user, _))| !user.is_listed(blacklist))
+                .for_each(|(cursor, (user, orgs_cursor))| {
+                    min_followers = user.followers;
+
+                    if !known_logins.contains(&user.login) {
+                        if let Some(orgs_cursor) = orgs_cursor {
+                            pending_orgs.push((users.len(), orgs_cursor));
+                        }
+
+                        known_logins.insert(user.login.clone());
+                        users.push(user);
+                    }
+                    last_cursor = Some(cursor);
+                });
+
+            fetch_pending_organizations(client, limiter, &mut users, pending_orgs.drain(..));
+
+            debug!("{}: Progress {}/{}", location.title, users.len(), USERS);
+
+            if users.len() >= USERS {
+                users.truncate(USERS);
+                break 'outer;
+            }
+
+            // Users are sorted by followers so the rest are below the floor
+            if floor.is_some_and(|floor| min_followers < floor) {
+                debug!("{}: Reached the follower floor", location.title);
+                break 'outer;
+            }
+        }
+    }
+
+    Ok((users, min_followers))
+}
+
+/// Creates a user from the fetched user fields, providing the cursor of the
+/// next page of organizations when the user has more than the first page
+///
+/// # Arguments
+/// * user - The fetched user fields
+fn create_user(user: users_query::UserFields) -> (User, Option<String>) {
+    let contrib_count = user
+        .contributions_collection
+        .contribution_calendar
+        .total_contributions;
+    let priv_contrib_count = user.contributions_collection.restricted_contributions_count;
+    let pub_contrib_count = contrib_count - priv_contrib_count;
+
+    let orgs_total = user.organizations.total_count;
+    let orgs_page = user.organizations.page_info;
+    let orgs = if let Some(orgs) = user.organizations.nodes {
+        orgs.into_iter()
+            .flatten()
+            .map(|value| value.login)
+            .collect()
+    } else {
+        Vec::with_capacity(0)
+    };
+    let orgs_cursor = orgs_page
+        .has_next_page
+        .then_some(orgs_page.end_cursor)
+        .flatten();
+
+    let social_accounts = user
+        .social_accounts
+        .nodes
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|account| SocialAccount {
+            provider: provider_name(account.provider),
+            url: account.url,
+            display_name: account.display_name,
+        })
+        .collect();
+
+    let user = User {
+        id: Some(user.id),
+        database_id: user.database_id,
+        login: user.login,
+        previous_logins: Vec::new(),
+        avatar: user.avatar_url,
+        name: user.name,
+        company: user.company,
+        bio: user.bio.filter(|bio| !bio.is_empty()),
+        website: user.website_url,
+        twitter: user.twitter_username,
+        social_accounts,
+        created_at: Some(user.created_at),
+        hireable: Some(user.is_hireable),
+        sponsors_listing: Some(user.has_sponsors_listing),
+        orgs,
+        orgs_total,
+        languages: Vec::new(),
+        followers: user.followers.total_count,
+        contribs: Some(contrib_count),
+        pub_contribs: pub_contrib_count,
+        priv_contribs: Some(priv_contrib_count),
+        priv_contribs_band: None,
+        commits: user.contributions_collection.total_commit_contributions,
+        pull_requests: user
+            .contributions_collection
+            .total_pull_request_contributions,
+    };
+
+    (user, orgs_cursor)
+}
+
+/// Fetches the remaining organizations of users with more
+/// organizations than the first page
+///
+/// # Arguments
+/// * client - The client to make the graphql requests
+/// * limiter - The shared rate limiter
+/// * users - The users the pending organizations are for
+/// * pending - The index of each user and the cursor of their next page
+fn fetch_pending_organizations(
+    client: &GitHubClient,
+    limiter: &RateLimiter,
+    users: &mut [User],
+    pending: impl IntoIterator<Item = (usize, String)>,
+) {
+    for (index, cursor) in pending {
+        let user = &mut users[index];
+        match fetch_organizations(client, limiter, &user.login, Some(cursor)) {
+            Ok(orgs) => user.orgs.extend(orgs),
+            Err(err) => warn!(
+                "Failed to fetch remaining organizations for {}: {}",
+                user.login, err
+            ),
+        }
+    }
+}
+
+/// Builds the search query for the users of a location preset
+///
+/// # Arguments
+/// * location - The location data for the query
+/// * max_followers - Exclusive maximum number of followers of the users
+pub fn build_query(location: &LocationPreset, max_followers: Option<i64>) -> String {
+    let mut query = String::new();
+
+    for location in location.include {
+        query.push_str(" location:");
+        query.push_str(&location_value(location));
+    }
+
+    for location in location.exclude {
+        query.push_str(" -location:");
+        query.push_str(&location_value(location));
+    }
+
+    if let Some(max_followers) = max_followers {
+        query.push_str(" followers:<");
+        query.push_str(&max_followers.to_string());
+    }
+
+    query.push_str(" sort:followers-desc");
+    query.trim_start().to_string()
+}
+
+/// Formats a location as the value of a location qualifier, locations
+/// containing anything other than letters, numbers, dashes and dots
+/// (Such as spaces or commas) are quoted. Double quotes can't be escaped within
+/// a search so they are removed
+///
+/// # Arguments
+/// * location - The location to format
+fn location_value(location: &str) -> String {
+    let location = location
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace('"', "");
+
+    if location
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '-' || c == '.')
+    {
+        location
+    } else {
+        format!("\"{}\"", location)
+    }
+}
+
+/// Checks the query of a preset against the GitHub search limits,
+/// providing a description of each limit that is exceeded
+///
+/// # Arguments
+/// * location - The location data for the query
+pub fn query_problems(location: &LocationPreset) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    let length = build_query(location, Some(FOLLOWERS_PLACEHOLDER))
+        .chars()
+        .count();
+    if length > MAX_QUERY_LENGTH {
+        problems.push(format!(
+            "Query length {} is over the limit of {}",
+            length, MAX_QUERY_LENGTH
+        ));
+    }
+
+    problems
+}
+
+/// Splits a preset into presets for groups of its included locations
+/// with queries that are within the GitHub search limits. The excluded
+/// locations are kept for every group
+///
+/// # Arguments
+/// * location - The preset to split
+pub fn split_preset(location: &LocationPreset) -> Vec<LocationPreset> {
+    let part = |include: &'static [&'static str]| LocationPreset {
+        title: location.title,
+        include,
+        exclude: location.exclude,
+    };
+
+    if query_problems(location).is_empty() {
+        return vec![part(location.include)];
+    }
+
+    let mut parts = Vec::new();
+    let mut start = 0;
+    while start < location.include.len() {
+        // Groups always have at least one location even if its query
+        // is still too long, this is reported by preset validation
+        let mut end = start + 1;
+        while end < location.include.len()
+            && query_problems(&part(&location.include[start..end + 1])).is_empty()
+        {
+            end += 1;
+        }
+
+        parts.push(part(&location.include[start..end]));
+        start = end;
+    }
+    parts
+}
+
+/// Counts the number of users matching a search query
+///
+/// # Arguments
+/// * client - The client to make the graphql requests
+/// * limiter - The shared rate limiter
+/// * query - The search query to count the users of
+pub fn count_users(
+    client: &GitHubClient,
+    limiter: &RateLimiter,
+    query: &str,
+) -> Result<i64, SearchError> {
+    let data = request::<UserCountQuery>(client, limiter, || user_count_query::Variables {
+        query: query.to_string(),
+    })?;
+    Ok(data.search.user_count)
+}
+
+/// Provides the lowercase name of a social account provider
+fn provider_name(provider: users_query::SocialAccountProvider) -> String {
+    use users_query::SocialAccountProvider as Provider;
+
+    let name = match provider {
+        Provider::FACEBOOK => "facebook",
+        Provider::GENERIC => "generic",
+        Provider::HOMETOWN => "hometown",
+        Provider::INSTAGRAM => "instagram",
+        Provider::LINKEDIN => "linkedin",
+        Provider::MASTODON => "mastodon",
+        Provider::REDDIT => "reddit",
+        Provider::TWITCH => "twitch",
+        Provider::TWITTER => "twitter",
+        Provider::YOUTUBE => "youtube",
+        Provider::Other(name) => return name.to_lowercase(),
+    };
+    name.to_string()
+}
+
+/// Fetches the organizations of a user following the provided cursor
+///
+/// # Arguments
+/// * client - The client to make the graphql requests
+/// * limiter - The shared rate limiter
+/// * login - The login of the user
+/// * after - The cursor to fetch organizations after
+fn fetch_organizations(
+    client: &GitHubClient,
+    limiter: &RateLimiter,
+    login: &str,
+    mut after: Option<String>,
+) -> Result<Vec<String>, SearchError> {
+    let mut orgs = Vec::new();
+
+    loop {
+        let data = request::<UserOrganizationsQuery>(client, limiter, || {
+            user_organizations_query::Variables {
+                login: login.to_string(),
+                after: after.clone(),
+            }
+        })?;
+
+        let Some(user) = data.user else {
+            return Err(SearchError::MissingData);
+        };
+
+        let page = user.organizations;
+        orgs.extend(
+            page.nodes
+                .into_iter()
+                .flatten()
+                .flatten()
+                .map(|org| org.login),
+        );
+
+        if !page.page_info.has_next_page {
+            break;
+        }
+        after = page.page_info.end_cursor;
+    }
+
+    debug!(
+        "Fetched {} additional organizations for {}",
+        orgs.len(),
+        login
+    );
+    Ok(orgs)
+}
+
+/// Fetches the current details of the users with the provided keys in
+/// batches. Users that no longer exist or couldn't be fetched are
+/// reported separately
+///
+/// # Arguments
+/// * client - The client to make the graphql requests
+/// * limiter - The shared rate limiter
+/// * batcher - The batch fetcher to fetch the users with
+/// * lookup - How the users are looked up by their keys
+/// * keys - The logins or node IDs of the users to fetch
+pub fn fetch_users(
+    client: &GitHubClient,
+    limiter: &RateLimiter,
+    batcher: &BatchFetcher,
+    lookup: Lookup,
+    keys: &[String],
+) -> Batched<User> {
+    let batched =
+        batcher.fetch::<users_query::UserFields>(client, limiter, &USER_FIELDS, lookup, keys);
+
+    let mut fetched_keys = Vec::with_capacity(batched.users.len());
+    let mut users = Vec::with_capacity(batched.users.len());
+    let mut pending_orgs = Vec::new();
+    for (key, user) in batched.users {
+        let (user, orgs_cursor) = create_user(user);
+        if let Some(orgs_cursor) = orgs_cursor {
+            pending_orgs.push((users.len(), orgs_cursor));
+        }
+        fetched_keys.push(key);
+        users.push(user);
+    }
+
+    fetch_pending_organizations(client, limiter, &mut users, pending_orgs);
+
+    Batched {
+        users: fetched_keys.into_iter().zip(users).collect(),
+        missing: batched.missing,
+        failed: batched.failed,
+    }
+}
+
+/// Fetches the top languages of each of the provided users in batches,
+/// users whose languages couldn't be fetched are left without any languages
+///
+/// # Arguments
+/// * client - The client to make the graphql requests
+/// * limiter - The shared rate limiter
+/// * batcher - The batch fetcher to fetch the languages with
+/// * users - The users to fetch the languages of
+pub fn fetch_languages(
+    client: &GitHubClient,
+    limiter: &RateLimiter,
+    batcher: &BatchFetcher,
+    users: &mut [User],
+) {
+    let logins: Vec<String> = users.iter().map(|user| user.login.clone()).collect();
+    let mut batched = batcher.fetch::<user_languages_query::UserLanguages>(
+        client,
+        limiter,
+        &USER_LANGUAGES,
+        Lookup::Login,
+        &logins,
+    );
+
+    for user in users {
+        if let Some(languages) = batched.users.remove(&user.login) {
+            user.languages = user_languages(languages);
+        }
+    }
+}
+
+/// Works out the languages of the repositories a user committed to in
+/// the last year, each repository contributes its commits split between
+/// its languages by their size
+///
+/// # Arguments
+/// * user - The fetched languages of the user
+fn user_languages(user: user_languages_query::UserLanguages) -> Vec<LanguageShare> {
+    let mut weights: HashMap<String, f64> = HashMap::new();
+    for contribution in user
+        .contributions_collection
+        .commit_contributions_by_repository
+    {
+        let commits = contribution.contributions.total_count as f64;
+        let Some(languages) = contribution.repository.languages else {
+            continue;
+        };
+        if languages.total_size <= 0 {
+            continue;
+        }
+
+        for edge in languages.edges.into_iter().flatten().flatten() {
+            let share = edge.size as f64 / languages.total_size as f64;
+            *weights.entry(edge.node.name).or_default() += commits * share;
+        }
+    }
+
+    language_shares(weights)
+}
+
+/// Makes a GraphQL request, retrying failed requests until
+/// [`MAX_ATTEMPTS`] is reached
+///
+/// # Arguments
+/// * client - The client to make the graphql requests
+/// * limiter - The shared rate limiter
+/// * variables - Function creating the variables for each attempt
+fn request<Q: GraphQLQuery>(
+    client: &GitHubClient,
+    limiter: &RateLimiter,
+    variables: impl Fn() -> Q::Variables,
+) -> Result<Q::ResponseData, SearchError> {
+    send(limiter, REQUEST_COST, MAX_ATTEMPTS, || {
+        client.query::<Q>(variables())
+    })
+}
+
+/// Sends a GraphQL request, retrying failed requests until
+/// the maximum number of attempts is reached
+///
+/// # Arguments
+/// * limiter - The shared rate limiter
+/// * cost - The number of rate limit points the request costs
+/// * max_attempts - The number of attempts before the request fails
+/// * query - Function sending the request for each attempt
+pub fn send<T>(
+    limiter: &RateLimiter,
+    cost: u32,
+    max_attempts: usize,
+    query: impl Fn() -> Result<Response<T>, reqwest::Error>,
+) -> Result<T, SearchError> {
+    let mut attempts = 0;
+
+    loop {
+        let res = {
+            let _permit = limiter.acquire(cost);
+            query()
+        };
+
+        let err = match res {
+            Ok(res) => match (res.errors, res.data) {
+                (Some(errors), _) if !errors.is_empty() => {
+                    error!("Request errored: {:?}", errors);
+                    SearchError::RequestErrors
+                }
+                (_, Some(data)) => return Ok(data),
+                (_, None) => SearchError::MissingData,
+            },
+            Err(err) => SearchError::FailedRequest(err),
+        };
+
+        attempts += 1;
+        if attempts >= max_attempts {
+            return Err(err);
+        }
+
+        error!("Failed request (retry in 10s): {}", err);
+
+        // Sleep for 10 seconds before trying again
+        sleep(Duration::from_secs(10));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::preset::PRESETS;
+
+    fn preset(include: &'static [&'static str]) -> LocationPreset {
+        LocationPreset {
+            title: "Test",
+            include,
+            exclude: &["nowhere"],
+        }
+    }
+
+    #[test]
+    fn location_value_quotes_only_when_needed() {
+        assert_eq!(location_value("auckland"), "auckland");
+        assert_eq!(location_value("wellington-nz"), "wellington-nz");
+        assert_eq!(location_value("u.s.a"), "u.s.a");
+        assert_eq!(location_value("são"), "são");
+        assert_eq!(location_value("new zealand"), "\"new zealand\"");
+        assert_eq!(location_value("auckland, nz"), "\"auckland, nz\"");
+    }
+
+    #[test]
+    fn location_value_normalizes_whitespace_and_quotes() {
+        assert_eq!(
+            location_value("  palmerston \t north "),
+            "\"palmerston north\""
+        );
+        assert_eq!(location_value("\"auckland\""), "auckland");
+    }
+
+    #[test]
+    fn split_preset_keeps_small_presets_whole() {
+        let location = preset(&["auckland", "wellington", "christchurch"]);
+        let parts = split_preset(&location);
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].include, location.include);
+    }
+
+    #[test]
+    fn split_preset_splits_long_presets_within_limits() {
+        let include: &'static [&'static str] = (0..40)
+            .map(|index| &*format!("a very long location name number {}", index).leak())
+            .collect::<Vec<_>>()
+            .leak();
+        let location = preset(include);
+        assert!(!query_problems(&location).is_empty());
+
+        let parts = split_preset(&location);
+        assert!(parts.len() > 1);
+
+        let mut include = Vec::new();
+        for part in &parts {
+            assert!(query_problems(part).is_empty());
+            assert_eq!(part.exclude, location.exclude);
+            include.extend_from_slice(part.include);
+        }
+        assert_eq!(include, location.include);
+    }
+
+    #[test]
+    fn split_preset_isolates_locations_that_are_too_long() {
+        let long = "x ".repeat(MAX_QUERY_LENGTH).leak().trim_end();
+        let include: &'static [&'static str] = vec!["auckland", long, "wellington"].leak();
+        let parts = split_preset(&preset(include));
+        assert!(parts.iter().any(|part| part.include == [long]));
+        assert_eq!(
+            parts.iter().map(|part| part.include.len()).sum::<usize>(),
+            3
+        );
+    }
+
+    #[test]
+    fn split_presets_are_within_limits() {
+        for location in PRESETS {
+            for part in split_preset(location) {
+                assert!(
+                    part.include.len() == 1 || query_problems(&part).is_empty(),
+                    "{} has a part beyond the limits",
+                    location.title
+                );
+            }
+        }
+    }
+}
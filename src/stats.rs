@@ -0,0 +1,146 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::output::User;
+
+/// Lower bounds of the follower distribution buckets
+const FOLLOWER_BUCKETS: &[i64] = &[0, 50, 100, 250, 500, 1000, 2500, 5000];
+
+/// Aggregate statistics for the users on a board
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct Stats {
+    /// Number of users on the board
+    pub users: usize,
+    /// Summary of the commits made by the users
+    pub commits: Summary,
+    /// Summary of the total contributions made by the users, users with
+    /// redacted private contributions are left out
+    pub contribs: Summary,
+    /// Summary of the public contributions made by the users
+    pub pub_contribs: Summary,
+    /// Summary of the private contributions made by the users, users
+    /// with redacted private contributions are left out
+    pub priv_contribs: Summary,
+    /// Share of all contributions that are public (0 to 1), users with
+    /// redacted private contributions are left out
+    pub public_ratio: f64,
+    /// Number of users with no public contributions
+    pub zero_public_contribs: usize,
+    /// Summary of the number of followers of the users
+    pub followers: Summary,
+    /// Number of users within each range of followers
+    pub follower_distribution: Vec<Bucket>,
+}
+
+/// Summary of a value across all the users
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct Summary {
+    pub total: i64,
+    pub mean: f64,
+    pub median: f64,
+    pub min: i64,
+    pub max: i64,
+}
+
+/// Number of users with a value within a range
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct Bucket {
+    /// Inclusive lower bound of the range
+    pub min: i64,
+    /// Inclusive upper bound of the range, missing for the last range
+    pub max: Option<i64>,
+    /// Number of users within the range
+    pub users: usize,
+}
+
+impl Stats {
+    /// Computes the statistics for the provided users
+    ///
+    /// # Arguments
+    /// * users - The users to compute the statistics for
+    pub fn from_users(users: &[User]) -> Self {
+        let commits = Summary::of(users, |user| Some(user.commits));
+        let contribs = Summary::of(users, |user| user.contribs);
+        let pub_contribs = Summary::of(users, |user| Some(user.pub_contribs));
+        let priv_contribs = Summary::of(users, |user| user.priv_contribs);
+        let followers = Summary::of(users, |user| Some(user.followers));
+
+        // Only users with known totals are compared so that redacted
+        // private contributions aren't counted as public
+        let known_public: i64 = users
+            .iter()
+            .filter(|user| user.contribs.is_some())
+            .map(|user| user.pub_contribs)
+            .sum();
+        let public_ratio = if contribs.total > 0 {
+            known_public as f64 / contribs.total as f64
+        } else {
+            0.0
+        };
+
+        let zero_public_contribs = users.iter().filter(|user| user.pub_contribs == 0).count();
+
+        let follower_distribution = FOLLOWER_BUCKETS
+            .iter()
+            .enumerate()
+            .map(|(index, &min)| {
+                let max = FOLLOWER_BUCKETS.get(index + 1).map(|next| next - 1);
+                let users = users
+                    .iter()
+                    .filter(|user| {
+                        user.followers >= min && max.is_none_or(|max| user.followers <= max)
+                    })
+                    .count();
+                Bucket { min, max, users }
+            })
+            .collect();
+
+        Self {
+            users: users.len(),
+            commits,
+            contribs,
+            pub_contribs,
+            priv_contribs,
+            public_ratio,
+            zero_public_contribs,
+            followers,
+            follower_distribution,
+        }
+    }
+}
+
+impl Summary {
+    /// Summarizes a value across the provided users, users without
+    /// the value are left out
+    ///
+    /// # Arguments
+    /// * users - The users to summarize
+    /// * value - Function providing the value for a user
+    fn of(users: &[User], value: impl Fn(&User) -> Option<i64>) -> Self {
+        let mut values: Vec<i64> = users.iter().filter_map(value).collect();
+        values.sort_unstable();
+
+        let total: i64 = values.iter().sum();
+        let count = values.len();
+
+        let mean = if count > 0 {
+            total as f64 / count as f64
+        } else {
+            0.0
+        };
+
+        let median = match count {
+            0 => 0.0,
+            count if count % 2 == 0 => (values[count / 2 - 1] + values[count / 2]) as f64 / 2.0,
+            count => values[count / 2] as f64,
+        };
+
+        Self {
+            total,
+            mean,
+            median,
+            min: values.first().copied().unwrap_or_default(),
+            max: values.last().copied().unwrap_or_default(),
+        }
+    }
+}